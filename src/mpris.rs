@@ -0,0 +1,153 @@
+use std::thread;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use zbus::{dbus_interface, ConnectionBuilder};
+use zbus::zvariant::{OwnedValue, Value};
+
+use crate::types::Message;
+use super::MainMessage;
+
+/// Messages sent from the MPRIS D-Bus interface back to the main
+/// controller, translated from the standard `org.mpris.MediaPlayer2.Player`
+/// method calls. The main controller is responsible for actually driving
+/// playback in response to these.
+#[derive(Debug)]
+pub enum MprisMsg {
+    Play,
+    Pause,
+    PlayPause,
+    Next,
+    Previous,
+    Stop,
+}
+
+/// Metadata about the currently playing episode, published to the MPRIS
+/// `Metadata` property whenever playback changes.
+#[derive(Debug, Clone)]
+pub struct MprisMetadata {
+    pub title: String,
+    pub podcast_title: String,
+    /// Episode duration in seconds, matching `Episode::duration`.
+    pub duration: Option<i64>,
+    pub art_url: Option<String>,
+}
+
+/// Holds the state shared between the D-Bus interface and the rest of
+/// shellcaster. `zbus` hands out `&mut self` to interface methods on its
+/// own thread, so this struct owns the channel back to the main
+/// controller directly.
+struct MprisPlayer {
+    tx_to_main: mpsc::Sender<Message>,
+    metadata: Option<MprisMetadata>,
+    playing: bool,
+}
+
+#[dbus_interface(name = "org.mpris.MediaPlayer2.Player")]
+impl MprisPlayer {
+    fn play(&mut self) {
+        let _ = self.tx_to_main.send(Message::Mpris(MprisMsg::Play));
+    }
+
+    fn pause(&mut self) {
+        let _ = self.tx_to_main.send(Message::Mpris(MprisMsg::Pause));
+    }
+
+    #[dbus_interface(name = "PlayPause")]
+    fn play_pause(&mut self) {
+        let _ = self.tx_to_main.send(Message::Mpris(MprisMsg::PlayPause));
+    }
+
+    fn next(&mut self) {
+        let _ = self.tx_to_main.send(Message::Mpris(MprisMsg::Next));
+    }
+
+    fn previous(&mut self) {
+        let _ = self.tx_to_main.send(Message::Mpris(MprisMsg::Previous));
+    }
+
+    fn stop(&mut self) {
+        let _ = self.tx_to_main.send(Message::Mpris(MprisMsg::Stop));
+    }
+
+    #[dbus_interface(property)]
+    fn playback_status(&self) -> String {
+        if self.playing {
+            "Playing".to_string()
+        } else {
+            "Paused".to_string()
+        }
+    }
+
+    #[dbus_interface(property)]
+    fn metadata(&self) -> std::collections::HashMap<String, OwnedValue> {
+        let mut map = std::collections::HashMap::new();
+        if let Some(md) = &self.metadata {
+            map.insert("xesam:title".to_string(), Value::from(md.title.clone()).to_owned());
+            map.insert("xesam:album".to_string(), Value::from(md.podcast_title.clone()).to_owned());
+            if let Some(art) = &md.art_url {
+                map.insert("mpris:artUrl".to_string(), Value::from(art.clone()).to_owned());
+            }
+            if let Some(duration) = md.duration {
+                // mpris:length is required to be an i64 of microseconds
+                let micros = duration.saturating_mul(1_000_000);
+                map.insert("mpris:length".to_string(), Value::from(micros).to_owned());
+            }
+        }
+        return map;
+    }
+}
+
+/// Spawns the MPRIS2 D-Bus thread, alongside `UI::spawn`. The thread owns
+/// a `zbus` connection exposing `org.mpris.MediaPlayer2.Player`, forwards
+/// incoming method calls to the main controller as `Message::Mpris(...)`,
+/// and applies `MainMessage` updates (new metadata, play/pause state)
+/// pushed out from the main controller whenever `UiMsg::Play` fires.
+pub fn spawn(rx_from_main: mpsc::Receiver<MainMessage>, tx_to_main: mpsc::Sender<Message>) -> thread::JoinHandle<()> {
+    return thread::spawn(move || {
+        let player = MprisPlayer {
+            tx_to_main,
+            metadata: None,
+            playing: false,
+        };
+
+        let connection = match ConnectionBuilder::session()
+            .and_then(|b| b.name("org.mpris.MediaPlayer2.shellcaster"))
+            .and_then(|b| b.serve_at("/org/mpris/MediaPlayer2", player))
+            .and_then(|b| b.build())
+        {
+            Ok(conn) => conn,
+            Err(_) => return,  // no session bus available; run silently disabled
+        };
+
+        let mut message_iter = rx_from_main.try_iter();
+        loop {
+            if let Some(message) = message_iter.next() {
+                match message {
+                    MainMessage::MprisMetadata(md) => {
+                        if let Ok(iface_ref) = connection
+                            .object_server()
+                            .interface::<_, MprisPlayer>("/org/mpris/MediaPlayer2")
+                        {
+                            let mut iface = iface_ref.get_mut();
+                            iface.metadata = Some(md);
+                        }
+                    },
+                    MainMessage::MprisPlaybackStatus(playing) => {
+                        if let Ok(iface_ref) = connection
+                            .object_server()
+                            .interface::<_, MprisPlayer>("/org/mpris/MediaPlayer2")
+                        {
+                            let mut iface = iface_ref.get_mut();
+                            iface.playing = playing;
+                        }
+                    },
+                    MainMessage::UiTearDown => break,
+                    _ => (),
+                }
+            }
+
+            thread::sleep(Duration::from_millis(10));
+        }
+    });
+}
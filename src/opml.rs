@@ -0,0 +1,147 @@
+use chrono::Utc;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+use crate::types::{LockVec, Podcast};
+
+impl LockVec<Podcast> {
+    /// Serializes every subscribed podcast to an OPML 2.0 document --
+    /// the standard interchange format every other podcatcher supports,
+    /// so it makes switching to/from shellcaster painless.
+    pub fn to_opml(&self) -> String {
+        let podcasts = self.borrow();
+        let mut body = String::new();
+        for pod in podcasts.iter() {
+            body.push_str(&format!(
+                "    <outline type=\"rss\" text=\"{title}\" title=\"{title}\" xmlUrl=\"{url}\"/>\n",
+                title = escape_xml(&pod.title),
+                url = escape_xml(&pod.url),
+            ));
+        }
+
+        return format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <opml version=\"2.0\">\n\
+             <head>\n\
+             <title>shellcaster subscriptions</title>\n\
+             <dateCreated>{date}</dateCreated>\n\
+             </head>\n\
+             <body>\n\
+             {body}\
+             </body>\n\
+             </opml>\n",
+            date = Utc::now().to_rfc2822(),
+            body = body,
+        );
+    }
+}
+
+/// Parses an OPML document (as exported by shellcaster or any other
+/// podcatcher) into skeleton `Podcast` structs -- just `title` and `url`
+/// filled in, with empty `episodes` -- ready to be synced to pull in
+/// the rest of their data. `<outline>` elements nested inside folder
+/// outlines are picked up the same as top-level ones: `quick_xml`
+/// streams events regardless of nesting depth, so there's no separate
+/// recursive walk to write.
+pub fn from_opml(xml: &str) -> Result<Vec<Podcast>, String> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut podcasts = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event(&mut buf) {
+            Ok(Event::Empty(ref e)) | Ok(Event::Start(ref e)) if e.name() == b"outline" => {
+                let mut title = None;
+                let mut xml_url = None;
+                for attr in e.attributes().filter_map(Result::ok) {
+                    let value = attr.unescape_and_decode_value(&reader)
+                        .map_err(|err| format!("invalid OPML: {}", err))?;
+                    match attr.key {
+                        b"title" | b"text" if title.is_none() => title = Some(value),
+                        b"xmlUrl" => xml_url = Some(value),
+                        _ => (),
+                    }
+                }
+
+                if let Some(url) = xml_url {
+                    podcasts.push(Podcast {
+                        id: None,
+                        title: title.unwrap_or_else(|| url.clone()),
+                        url: url,
+                        description: None,
+                        author: None,
+                        explicit: None,
+                        last_checked: Utc::now(),
+                        episodes: LockVec::new(Vec::new()),
+                        any_unplayed: false,
+                    });
+                }
+            },
+            Ok(Event::Eof) => break,
+            Err(err) => return Err(format!("invalid OPML: {}", err)),
+            _ => (),
+        }
+        buf.clear();
+    }
+
+    return Ok(podcasts);
+}
+
+/// Escapes the characters OPML (like any XML) requires escaped inside
+/// an attribute value.
+fn escape_xml(text: &str) -> String {
+    return text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_title_and_url() {
+        let podcasts = LockVec::new(vec![Podcast {
+            id: Some(1),
+            title: "Test & Pod".to_string(),
+            url: "https://example.com/feed.xml".to_string(),
+            description: None,
+            author: None,
+            explicit: None,
+            last_checked: Utc::now(),
+            episodes: LockVec::new(Vec::new()),
+            any_unplayed: false,
+        }]);
+
+        let opml = podcasts.to_opml();
+        let parsed = from_opml(&opml).unwrap();
+
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].title, "Test & Pod");
+        assert_eq!(parsed[0].url, "https://example.com/feed.xml");
+    }
+
+    #[test]
+    fn reads_nested_folder_outlines() {
+        let xml = r#"<?xml version="1.0"?>
+            <opml version="2.0">
+            <body>
+                <outline text="Tech">
+                    <outline type="rss" text="Feed One" xmlUrl="https://a.example.com/feed"/>
+                    <outline type="rss" text="Feed Two" xmlUrl="https://b.example.com/feed"/>
+                </outline>
+            </body>
+            </opml>"#;
+
+        let parsed = from_opml(xml).unwrap();
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].url, "https://a.example.com/feed");
+        assert_eq!(parsed[1].url, "https://b.example.com/feed");
+    }
+}
@@ -0,0 +1,361 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::thread;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use rodio::{Decoder, OutputStream, Sink, Source};
+
+/// How far from the end of the current episode (in wall-clock time) the
+/// next queued episode is decoded and appended to the sink, so the
+/// transition between episodes has no audible gap or re-buffer stall.
+const PRELOAD_LEAD: Duration = Duration::from_secs(2);
+
+/// Assumed average bitrate used to estimate an episode's total duration
+/// from its file size when the decoder can't report `total_duration()`
+/// itself (common for some streamed/VBR formats). 128kbps is a typical
+/// encoding rate for spoken-word podcast audio; the estimate only needs
+/// to be close enough to trigger preloading before playback reaches the
+/// real end of the file.
+const ASSUMED_BITRATE_BPS: u64 = 128_000;
+
+/// Estimates how long `path` plays for from its size on disk, for use
+/// when `Source::total_duration()` returns `None`. Returns `None` if the
+/// file's size can't be read.
+fn estimate_total_duration(path: &PathBuf) -> Option<Duration> {
+    let bytes = std::fs::metadata(path).ok()?.len();
+    let secs = (bytes * 8) as f64 / ASSUMED_BITRATE_BPS as f64;
+    return Some(Duration::from_secs_f64(secs));
+}
+
+/// Commands sent to the playback thread, accepted over an mpsc channel.
+#[derive(Debug)]
+pub enum PlayerCmd {
+    Play(PathBuf, String),
+    /// Replaces the play queue and immediately starts the first entry.
+    PlayAll(VecDeque<(PathBuf, String)>),
+    /// Appends an episode to the end of the play queue.
+    Enqueue(PathBuf, String),
+    Pause,
+    Resume,
+    Seek(i64),  // seconds; negative seeks backward
+    Volume(i8),  // delta, in percentage points
+    Stop,
+}
+
+/// Events emitted by the playback thread so the UI can keep its
+/// now-playing status line up to date.
+#[derive(Debug, Clone)]
+pub enum PlayerEvent {
+    Playing { title: String, elapsed: Duration, total: Option<Duration> },
+    /// The next queued episode has begun decoding and has been appended
+    /// to the sink, ahead of the current episode actually finishing.
+    Loading { title: String },
+    /// The previous episode's readable range has been fully consumed and
+    /// playback has moved on to the next queued entry (if any).
+    EndOfTrack { title: String },
+    Paused,
+    Progress { elapsed: Duration },
+    Finished,
+    Error(String),
+}
+
+/// An episode queued for gapless playback. Its total duration is not
+/// stored here; the playback thread tracks it alongside (`current_total`,
+/// and the duration paired with a `preloaded` entry).
+#[derive(Debug, Clone)]
+struct QueueItem {
+    path: PathBuf,
+    title: String,
+}
+
+/// Owns the playback thread and the channel used to send it commands.
+/// This replaces shelling out to an external player: decoding and
+/// mixing happen in-process via `rodio`, so play/pause/seek/volume, and
+/// now a whole-podcast play queue, are all driven from inside
+/// shellcaster.
+#[derive(Debug)]
+pub struct Player {
+    tx_cmd: mpsc::Sender<PlayerCmd>,
+}
+
+impl Player {
+    /// Spawns the playback thread and returns a handle for sending it
+    /// commands. `tx_event` is used by the thread to report state changes
+    /// (now playing, loading, end-of-track, paused, progress) back to
+    /// the UI.
+    pub fn spawn(tx_event: mpsc::Sender<PlayerEvent>) -> Player {
+        let (tx_cmd, rx_cmd) = mpsc::channel();
+
+        thread::spawn(move || {
+            // the stream must stay alive for the sink to produce sound
+            let (_stream, stream_handle) = match OutputStream::try_default() {
+                Ok(pair) => pair,
+                Err(e) => {
+                    let _ = tx_event.send(PlayerEvent::Error(e.to_string()));
+                    return;
+                },
+            };
+            let mut sink: Option<Sink> = None;
+            let mut volume: f32 = 1.0;
+            let mut started_at = Instant::now();
+            let mut paused_elapsed = Duration::from_secs(0);
+
+            // the play queue: episodes waiting to play after `current`
+            let mut queue: VecDeque<QueueItem> = VecDeque::new();
+            let mut current: Option<QueueItem> = None;
+            let mut current_total: Option<Duration> = None;
+            // set once the next item has been decoded and appended to
+            // the sink, ahead of `current` actually finishing
+            let mut preloaded: Option<(QueueItem, Option<Duration>)> = None;
+
+            let decode = |path: &PathBuf| -> Result<(Box<dyn Source<Item = i16> + Send>, Option<Duration>), String> {
+                let file = File::open(path).map_err(|e| e.to_string())?;
+                let source = Decoder::new(BufReader::new(file)).map_err(|e| e.to_string())?;
+                let total = source.total_duration().or_else(|| estimate_total_duration(path));
+                return Ok((Box::new(source), total));
+            };
+
+            loop {
+                match rx_cmd.recv_timeout(Duration::from_millis(250)) {
+                    Ok(PlayerCmd::Play(path, title)) => {
+                        queue.clear();
+                        preloaded = None;
+                        match decode(&path) {
+                            Ok((source, total)) => {
+                                let new_sink = Sink::try_new(&stream_handle).unwrap();
+                                new_sink.set_volume(volume);
+                                new_sink.append(source);
+                                sink = Some(new_sink);
+                                started_at = Instant::now();
+                                paused_elapsed = Duration::from_secs(0);
+                                current = Some(QueueItem { path, title: title.clone() });
+                                current_total = total;
+
+                                let _ = tx_event.send(PlayerEvent::Playing { title, elapsed: Duration::from_secs(0), total });
+                            },
+                            Err(e) => {
+                                let _ = tx_event.send(PlayerEvent::Error(e));
+                            },
+                        }
+                    },
+                    Ok(PlayerCmd::PlayAll(mut items)) => {
+                        queue.clear();
+                        preloaded = None;
+                        if let Some((path, title)) = items.pop_front() {
+                            queue = items.into_iter().map(|(path, title)| QueueItem { path, title }).collect();
+                            match decode(&path) {
+                                Ok((source, total)) => {
+                                    let new_sink = Sink::try_new(&stream_handle).unwrap();
+                                    new_sink.set_volume(volume);
+                                    new_sink.append(source);
+                                    sink = Some(new_sink);
+                                    started_at = Instant::now();
+                                    paused_elapsed = Duration::from_secs(0);
+                                    current = Some(QueueItem { path, title: title.clone() });
+                                    current_total = total;
+
+                                    let _ = tx_event.send(PlayerEvent::Playing { title, elapsed: Duration::from_secs(0), total });
+                                },
+                                Err(e) => {
+                                    let _ = tx_event.send(PlayerEvent::Error(e));
+                                },
+                            }
+                        }
+                    },
+                    Ok(PlayerCmd::Enqueue(path, title)) => {
+                        queue.push_back(QueueItem { path, title });
+                    },
+                    Ok(PlayerCmd::Pause) => {
+                        if let Some(s) = &sink {
+                            s.pause();
+                            paused_elapsed += started_at.elapsed();
+                            let _ = tx_event.send(PlayerEvent::Paused);
+                        }
+                    },
+                    Ok(PlayerCmd::Resume) => {
+                        if let Some(s) = &sink {
+                            s.play();
+                            started_at = Instant::now();
+                        }
+                    },
+                    Ok(PlayerCmd::Seek(delta)) => {
+                        if let Some(item) = current.clone() {
+                            let elapsed = paused_elapsed + started_at.elapsed();
+                            let target = (elapsed.as_secs_f64() + delta as f64).max(0.0);
+                            let target = Duration::from_secs_f64(target);
+
+                            match decode(&item.path) {
+                                Ok((source, _)) => {
+                                    let skipped: Box<dyn Source<Item = i16> + Send> =
+                                        Box::new(source.skip_duration(target));
+                                    let new_sink = Sink::try_new(&stream_handle).unwrap();
+                                    new_sink.set_volume(volume);
+                                    new_sink.append(skipped);
+                                    if let Some(s) = sink.take() {
+                                        s.stop();
+                                    }
+                                    sink = Some(new_sink);
+                                    started_at = Instant::now();
+                                    paused_elapsed = target;
+                                    // the old sink carried any preloaded
+                                    // next episode; put it back at the
+                                    // front of the queue so it isn't lost
+                                    if let Some((next, _)) = preloaded.take() {
+                                        queue.push_front(next);
+                                    }
+
+                                    let _ = tx_event.send(PlayerEvent::Playing {
+                                        title: item.title.clone(),
+                                        elapsed: target,
+                                        total: current_total,
+                                    });
+                                },
+                                Err(e) => {
+                                    let _ = tx_event.send(PlayerEvent::Error(e));
+                                },
+                            }
+                        }
+                    },
+                    Ok(PlayerCmd::Volume(delta)) => {
+                        volume = (volume + (delta as f32 / 100.0)).clamp(0.0, 2.0);
+                        if let Some(s) = &sink {
+                            s.set_volume(volume);
+                        }
+                    },
+                    Ok(PlayerCmd::Stop) => {
+                        if let Some(s) = sink.take() {
+                            s.stop();
+                        }
+                        queue.clear();
+                        preloaded = None;
+                        current = None;
+                        let _ = tx_event.send(PlayerEvent::Finished);
+                    },
+                    Err(mpsc::RecvTimeoutError::Timeout) => {
+                        if let (Some(_), Some(item)) = (&sink, &current) {
+                            let elapsed = paused_elapsed + started_at.elapsed();
+                            let sink_empty = sink.as_ref().map(|s| s.empty()).unwrap_or(true);
+
+                            // once we're close enough to the end of the
+                            // readable range, decode and append the next
+                            // queued episode so there's no gap or stall.
+                            // `current_total` is normally `Some` even when
+                            // the decoder's own `total_duration()` came
+                            // back empty, since `decode` falls back to a
+                            // file-size estimate; `None` here only means
+                            // that estimate itself failed (e.g. the file
+                            // became unreadable), in which case draining
+                            // the sink dry is the last signal left
+                            if preloaded.is_none() {
+                                let should_preload = match current_total {
+                                    Some(total) => total.saturating_sub(elapsed) <= PRELOAD_LEAD,
+                                    None => sink_empty,
+                                };
+                                if should_preload {
+                                    if let Some(next) = queue.pop_front() {
+                                        if let Ok((source, next_total)) = decode(&next.path) {
+                                            if let Some(s) = &sink {
+                                                s.append(source);
+                                            }
+                                            let _ = tx_event.send(PlayerEvent::Loading { title: next.title.clone() });
+                                            preloaded = Some((next, next_total));
+                                        }
+                                    }
+                                }
+                            }
+
+                            // the current episode's range has been fully
+                            // read; if we already preloaded the next one,
+                            // hand off to it with no re-buffering. as
+                            // above, `current_total` being `None` here is
+                            // the rare case where even the file-size
+                            // estimate failed, so the sink draining dry is
+                            // the only signal left that playback has
+                            // reached the end of the current episode
+                            let finished_current = match current_total {
+                                Some(total) => elapsed >= total,
+                                None => sink_empty,
+                            };
+                            if finished_current {
+                                if let Some((next, next_total)) = preloaded.take() {
+                                    let _ = tx_event.send(PlayerEvent::EndOfTrack { title: item.title.clone() });
+                                    started_at = Instant::now();
+                                    paused_elapsed = Duration::from_secs(0);
+                                    current_total = next_total;
+                                    let _ = tx_event.send(PlayerEvent::Playing {
+                                        title: next.title.clone(),
+                                        elapsed: Duration::from_secs(0),
+                                        total: next_total,
+                                    });
+                                    current = Some(next);
+                                } else if sink.as_ref().map(|s| s.empty()).unwrap_or(true) {
+                                    let _ = tx_event.send(PlayerEvent::EndOfTrack { title: item.title.clone() });
+                                    current = None;
+                                    current_total = None;
+                                    sink = None;
+                                    let _ = tx_event.send(PlayerEvent::Finished);
+                                }
+                            } else {
+                                let _ = tx_event.send(PlayerEvent::Progress { elapsed });
+                            }
+                        }
+                    },
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                }
+            }
+        });
+
+        return Player { tx_cmd };
+    }
+
+    /// Queues a single file for playback, replacing anything currently
+    /// playing or queued.
+    pub fn play(&self, path: PathBuf, title: String) {
+        let _ = self.tx_cmd.send(PlayerCmd::Play(path, title));
+    }
+
+    /// Replaces the play queue with `items` and starts playing the
+    /// first one immediately; the rest will play back to back with no
+    /// gap as each preceding episode finishes.
+    pub fn play_all(&self, items: VecDeque<(PathBuf, String)>) {
+        let _ = self.tx_cmd.send(PlayerCmd::PlayAll(items));
+    }
+
+    /// Appends an episode to the end of the current play queue.
+    pub fn enqueue(&self, path: PathBuf, title: String) {
+        let _ = self.tx_cmd.send(PlayerCmd::Enqueue(path, title));
+    }
+
+    pub fn pause(&self) {
+        let _ = self.tx_cmd.send(PlayerCmd::Pause);
+    }
+
+    pub fn resume(&self) {
+        let _ = self.tx_cmd.send(PlayerCmd::Resume);
+    }
+
+    pub fn seek(&self, delta_secs: i64) {
+        let _ = self.tx_cmd.send(PlayerCmd::Seek(delta_secs));
+    }
+
+    pub fn volume(&self, delta_pct: i8) {
+        let _ = self.tx_cmd.send(PlayerCmd::Volume(delta_pct));
+    }
+
+    pub fn stop(&self) {
+        let _ = self.tx_cmd.send(PlayerCmd::Stop);
+    }
+}
+
+/// Formats a `Duration` as `HH:MM:SS` for the now-playing status line.
+pub fn format_elapsed(dur: Duration) -> String {
+    let mut seconds = dur.as_secs();
+    let hours = seconds / 3600;
+    seconds -= hours * 3600;
+    let minutes = seconds / 60;
+    seconds -= minutes * 60;
+    return format!("{:02}:{:02}:{:02}", hours, minutes, seconds);
+}
@@ -1,17 +1,38 @@
+use std::cmp::Ordering;
 use std::path::PathBuf;
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::ops::{Bound, RangeBounds};
 use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::ui::UiMsg;
+use crate::ui::{ColorType, UiMsg};
 use crate::feeds::FeedMsg;
 use crate::downloads::DownloadMsg;
+use crate::mpris::MprisMsg;
 
 /// Defines interface used for both podcasts and episodes, to be
 /// used and displayed in menus.
 pub trait Menuable {
     fn get_title(&self, length: usize) -> String;
+
+    /// Returns the full, untruncated title, for matching against (e.g.,
+    /// search or filter queries) rather than display.
+    fn raw_title(&self) -> &str;
     fn is_played(&self) -> bool;
+
+    /// Maps this item's own state (played, downloaded, in progress...)
+    /// to the color pair and bold attribute a menu should draw its row
+    /// with, so e.g. downloaded episodes can be styled differently from
+    /// ones that are merely queued.
+    fn status_style(&self) -> (ColorType, bool);
+
+    /// Returns the full description/notes text for a menu's preview
+    /// pane, if there is any to show. Defaults to `None`.
+    fn preview_text(&self) -> Option<&str> {
+        return None;
+    }
 }
 
 /// Struct holding data about an individual podcast feed. This includes a
@@ -32,7 +53,7 @@ pub struct Podcast {
 impl Menuable for Podcast {
     /// Returns the title for the podcast, up to length characters.
     fn get_title(&self, length: usize) -> String {
-        let mut out = self.title.substring(0, length);
+        let mut out = self.title.substring_graphemes(0, length);
         // if the size available is big enough, we add the unplayed data
         // to the end
         if length > super::PODCAST_UNPLAYED_TOTALS_LENGTH {
@@ -45,18 +66,30 @@ impl Menuable for Podcast {
                 total = format!("{}", borrow.len());
             }
             let added_len = unplayed.len() + total.len() + 4;
-            out = out.substring(0, length-added_len);
+            out = out.substring_graphemes(0, length-added_len);
 
-            return format!("{}{:>width$}{}/{})", out, "(", unplayed, total, width=length-out.chars().count()-added_len+2);
+            return format!("{}{:>width$}{}/{})", out, "(", unplayed, total, width=length-out.graphemes(true).count()-added_len+2);
                 // this pads spaces between title and totals
         } else {
             return out.to_string();
         }
     }
 
+    fn raw_title(&self) -> &str {
+        return &self.title;
+    }
+
     fn is_played(&self) -> bool {
         return !self.any_unplayed;
     }
+
+    fn status_style(&self) -> (ColorType, bool) {
+        return (ColorType::Normal, self.any_unplayed);
+    }
+
+    fn preview_text(&self) -> Option<&str> {
+        return self.description.as_deref();
+    }
 }
 
 /// Struct holding data about an individual podcast episode. Most of this
@@ -78,7 +111,7 @@ pub struct Episode {
 
 impl Episode {
     /// Formats the duration in seconds into an HH:MM:SS format.
-    fn format_duration(&self) -> String {
+    pub fn format_duration(&self) -> String {
         return match self.duration {
             Some(dur) => {
                 let mut seconds = dur;
@@ -93,12 +126,41 @@ impl Episode {
     }
 }
 
+/// Matches `<itunes:duration>` in any of its three common forms --
+/// "HH:MM:SS", "MM:SS", or a bare number of seconds -- with the last
+/// capture group always seconds, the middle minutes, and the first
+/// hours.
+static DURATION_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:(?:(\d+):)?(\d+):)?(\d+)$").unwrap()
+});
+
+/// Parses an `<itunes:duration>` string -- "HH:MM:SS", "MM:SS", or a
+/// bare integer number of seconds -- into a total number of seconds.
+/// Returns `None` if `raw` doesn't match any of those forms, rather
+/// than panicking, so feeds with malformed durations can be skipped
+/// over, not blow up the whole parse.
+pub fn parse_duration(raw: &str) -> Option<i64> {
+    let caps = DURATION_RE.captures(raw.trim())?;
+
+    let hours: i64 = match caps.get(1) {
+        Some(m) => m.as_str().parse().ok()?,
+        None => 0,
+    };
+    let minutes: i64 = match caps.get(2) {
+        Some(m) => m.as_str().parse().ok()?,
+        None => 0,
+    };
+    let seconds: i64 = caps.get(3)?.as_str().parse().ok()?;
+
+    return Some(hours * 3600 + minutes * 60 + seconds);
+}
+
 impl Menuable for Episode {
     /// Returns the title for the episode, up to length characters.
     fn get_title(&self, length: usize) -> String {
         let out = match self.path {
-            Some(_) => format!("[D] {}", self.title.substring(0, length-4)),
-            None => self.title.substring(0, length).to_string(),
+            Some(_) => format!("[D] {}", self.title.substring_graphemes(0, length-4)),
+            None => self.title.substring_graphemes(0, length).to_string(),
         };
         if length > super::EPISODE_PUBDATE_LENGTH {
             let dur = self.format_duration();
@@ -109,54 +171,87 @@ impl Menuable for Episode {
                 let pd = pubdate.format("%F")
                     .to_string();
                 added_len = added_len + pd.len() + 3;
-                return format!("{}{:>width$}{}) [{}]", out.substring(0, length-added_len), "(", pd, dur, width=length-out.chars().count()-added_len+2);
+                return format!("{}{:>width$}{}) [{}]", out.substring_graphemes(0, length-added_len), "(", pd, dur, width=length-out.graphemes(true).count()-added_len+2);
             } else {
                 // just print duration
-                return format!("{}{:>width$}{}]", out.substring(0, length-added_len), "[", dur, width=length-out.chars().count()-added_len+2);
+                return format!("{}{:>width$}{}]", out.substring_graphemes(0, length-added_len), "[", dur, width=length-out.graphemes(true).count()-added_len+2);
             }
         } else if length > super::EPISODE_DURATION_LENGTH {
             let dur = self.format_duration();
             let added_len = dur.len() + 3;
-            return format!("{}{:>width$}{}]", out.substring(0, length-added_len), "[", dur, width=length-out.chars().count()-added_len+2);
+            return format!("{}{:>width$}{}]", out.substring_graphemes(0, length-added_len), "[", dur, width=length-out.graphemes(true).count()-added_len+2);
         } else {
             return out;
         }
     }
 
+    fn raw_title(&self) -> &str {
+        return &self.title;
+    }
+
     fn is_played(&self) -> bool {
         return self.played;
     }
+
+    fn status_style(&self) -> (ColorType, bool) {
+        if self.played {
+            return (ColorType::Normal, false);
+        } else if self.path.is_some() {
+            return (ColorType::Downloaded, true);
+        } else {
+            return (ColorType::Normal, true);
+        }
+    }
+
+    fn preview_text(&self) -> Option<&str> {
+        if self.description.is_empty() {
+            return None;
+        }
+        return Some(&self.description);
+    }
 }
 
 
 /// Struct used to hold a vector of data inside a reference-counted
-/// mutex, to allow for multiple owners of mutable data.
+/// read-write lock, to allow for multiple owners of mutable data.
 /// Primarily, the LockVec is used to provide methods that abstract
 /// away some of the logic necessary for borrowing and locking the
-/// Arc<Mutex<_>>.
+/// Arc<RwLock<_>>. Using a RwLock rather than a Mutex means the many
+/// read-only accesses done while rendering the UI (e.g.,
+/// `clone_podcast()`/`clone_episode()`/`id_to_index()`) don't block
+/// each other, and only contend with the occasional writer doing a
+/// feed sync or `replace()`.
 #[derive(Debug)]
 pub struct LockVec<T>
     where T: Clone {
-    data: Arc<Mutex<Vec<T>>>,
+    data: Arc<RwLock<Vec<T>>>,
 }
 
 impl<T: Clone> LockVec<T> {
     /// Create a new LockVec.
     pub fn new(data: Vec<T>) -> LockVec<T> {
         return LockVec {
-            data: Arc::new(Mutex::new(data)),
+            data: Arc::new(RwLock::new(data)),
         }
     }
 
-    /// Lock the LockVec for reading/writing.
-    pub fn borrow(&self) -> MutexGuard<Vec<T>> {
-        return self.data.lock().unwrap();
+    /// Lock the LockVec for reading. Multiple readers may hold this
+    /// lock at the same time.
+    pub fn borrow(&self) -> RwLockReadGuard<Vec<T>> {
+        return self.data.read().unwrap();
+    }
+
+    /// Lock the LockVec for writing. Used by `replace()` and by the
+    /// feed-sync code that rebuilds the underlying vector; excludes
+    /// all readers and writers for the duration of the borrow.
+    pub fn borrow_mut(&self) -> RwLockWriteGuard<Vec<T>> {
+        return self.data.write().unwrap();
     }
 
     /// Given an index in the vector, this takes a new T and replaces
     /// the old T at that position in the vector.
     pub fn replace(&self, index: usize, t: T) -> Result<(), &'static str> {
-        let mut borrowed = self.borrow();
+        let mut borrowed = self.borrow_mut();
         if index > 0 && index < borrowed.len() {
             borrowed[index] = t;
             return Ok(());
@@ -165,6 +260,28 @@ impl<T: Clone> LockVec<T> {
         }
     }
 
+    /// Sorts the backing vector in place using `cmp`. The DB keys in
+    /// each item's `id` aren't affected by the reorder, and
+    /// `id_to_index()` always walks the live vector rather than caching
+    /// positions, so "jump to podcast/episode by id" stays correct
+    /// after a sort with no extra bookkeeping.
+    pub fn sort_by(&self, cmp: impl FnMut(&T, &T) -> Ordering) {
+        let mut borrowed = self.borrow_mut();
+        borrowed.sort_by(cmp);
+    }
+
+    /// Returns the indices of the elements matching `pred`, without
+    /// cloning them -- e.g., for filtering a menu down to unplayed or
+    /// downloaded-only episodes.
+    pub fn filtered_indices(&self, pred: impl Fn(&T) -> bool) -> Vec<usize> {
+        let borrowed = self.borrow();
+        return borrowed.iter()
+            .enumerate()
+            .filter(|(_, val)| pred(val))
+            .map(|(i, _)| i)
+            .collect();
+    }
+
 }
 
 impl<T: Clone> Clone for LockVec<T> {
@@ -203,6 +320,11 @@ impl LockVec<Podcast> {
         let borrowed = self.borrow();
         return borrowed.iter().position(|val| val.id == Some(id));
     }
+
+    /// Sorts the podcast list alphabetically by title.
+    pub fn sort_podcasts_by_title(&self) {
+        self.sort_by(|a, b| a.title.cmp(&b.title));
+    }
 }
 
 impl LockVec<Episode> {
@@ -223,6 +345,12 @@ impl LockVec<Episode> {
         let borrowed = self.borrow();
         return borrowed.iter().position(|val| val.id == Some(id));
     }
+
+    /// Sorts the episode list by publication date, oldest first.
+    /// Episodes with no known pubdate sort before ones that have one.
+    pub fn sort_episodes_by_pubdate(&self) {
+        self.sort_by(|a, b| a.pubdate.cmp(&b.pubdate));
+    }
 }
 
 
@@ -233,6 +361,7 @@ pub enum Message {
     Ui(UiMsg),
     Feed(FeedMsg),
     Dl(DownloadMsg),
+    Mpris(MprisMsg),
 }
 
 
@@ -244,6 +373,13 @@ pub enum Message {
 pub trait StringUtils {
     fn substring(&self, start: usize, len: usize) -> &str;
     fn slice(&self, range: impl RangeBounds<usize>) -> &str;
+
+    /// Like `substring()`, but counts grapheme clusters rather than
+    /// scalar values, so a flag emoji, an accented character built out
+    /// of combining marks, etc. is never split in half -- which
+    /// `substring()` can do, since a single displayed character can be
+    /// made up of multiple `char`s.
+    fn substring_graphemes(&self, start: usize, len: usize) -> &str;
 }
 
 impl StringUtils for str {
@@ -271,6 +407,30 @@ impl StringUtils for str {
         }
         &self[byte_start..byte_end]
     }
+    fn substring_graphemes(&self, start: usize, len: usize) -> &str {
+        let mut char_pos = 0;
+        let mut byte_start = 0;
+        let mut it = self.graphemes(true);
+        loop {
+            if char_pos == start { break; }
+            if let Some(c) = it.next() {
+                char_pos += 1;
+                byte_start += c.len();
+            }
+            else { break; }
+        }
+        char_pos = 0;
+        let mut byte_end = byte_start;
+        loop {
+            if char_pos == len { break; }
+            if let Some(c) = it.next() {
+                char_pos += 1;
+                byte_end += c.len();
+            }
+            else { break; }
+        }
+        &self[byte_start..byte_end]
+    }
     fn slice(&self, range: impl RangeBounds<usize>) -> &str {
         let start = match range.start_bound() {
             Bound::Included(bound) | Bound::Excluded(bound) => *bound,
@@ -310,6 +470,30 @@ impl StringUtils for String {
         }
         &self[byte_start..byte_end]
     }
+    fn substring_graphemes(&self, start: usize, len: usize) -> &str {
+        let mut char_pos = 0;
+        let mut byte_start = 0;
+        let mut it = self.graphemes(true);
+        loop {
+            if char_pos == start { break; }
+            if let Some(c) = it.next() {
+                char_pos += 1;
+                byte_start += c.len();
+            }
+            else { break; }
+        }
+        char_pos = 0;
+        let mut byte_end = byte_start;
+        loop {
+            if char_pos == len { break; }
+            if let Some(c) = it.next() {
+                char_pos += 1;
+                byte_end += c.len();
+            }
+            else { break; }
+        }
+        &self[byte_start..byte_end]
+    }
     fn slice(&self, range: impl RangeBounds<usize>) -> &str {
         let start = match range.start_bound() {
             Bound::Included(bound) | Bound::Excluded(bound) => *bound,
@@ -322,4 +506,99 @@ impl StringUtils for String {
         } - start;
         self.substring(start, len)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substring_graphemes_keeps_combining_marks_whole() {
+        // "e\u{0301}" is "e" + a combining acute accent -- two `char`s,
+        // but a single grapheme cluster.
+        let title = "cafe\u{0301} time";
+        assert_eq!(title.substring_graphemes(0, 4), "cafe\u{0301}");
+    }
+
+    #[test]
+    fn substring_graphemes_truncates_shorter_than_available() {
+        let title = "short";
+        assert_eq!(title.substring_graphemes(0, 20), "short");
+    }
+
+    fn test_podcast(id: i64, title: &str) -> Podcast {
+        return Podcast {
+            id: Some(id),
+            title: title.to_string(),
+            url: "https://example.com/feed.xml".to_string(),
+            description: None,
+            author: None,
+            explicit: None,
+            last_checked: Utc::now(),
+            episodes: LockVec::new(Vec::new()),
+            any_unplayed: false,
+        };
+    }
+
+    #[test]
+    fn sort_podcasts_by_title_reorders_vector() {
+        let podcasts = LockVec::new(vec![
+            test_podcast(1, "Zebra Cast"),
+            test_podcast(2, "Apple Cast"),
+        ]);
+
+        podcasts.sort_podcasts_by_title();
+
+        let borrowed = podcasts.borrow();
+        assert_eq!(borrowed[0].title, "Apple Cast");
+        assert_eq!(borrowed[1].title, "Zebra Cast");
+    }
+
+    #[test]
+    fn id_to_index_is_correct_after_sort() {
+        let podcasts = LockVec::new(vec![
+            test_podcast(1, "Zebra Cast"),
+            test_podcast(2, "Apple Cast"),
+        ]);
+
+        podcasts.sort_podcasts_by_title();
+
+        assert_eq!(podcasts.id_to_index(2), Some(0));
+        assert_eq!(podcasts.id_to_index(1), Some(1));
+    }
+
+    #[test]
+    fn filtered_indices_returns_matching_positions() {
+        let podcasts = LockVec::new(vec![
+            test_podcast(1, "Zebra Cast"),
+            test_podcast(2, "Apple Cast"),
+            test_podcast(3, "Apple Podcast"),
+        ]);
+
+        let indices = podcasts.filtered_indices(|pod| pod.title.starts_with("Apple"));
+
+        assert_eq!(indices, vec![1, 2]);
+    }
+
+    #[test]
+    fn parses_hh_mm_ss() {
+        assert_eq!(parse_duration("01:02:03"), Some(3723));
+    }
+
+    #[test]
+    fn parses_mm_ss() {
+        assert_eq!(parse_duration("02:03"), Some(123));
+    }
+
+    #[test]
+    fn parses_bare_seconds() {
+        assert_eq!(parse_duration("45"), Some(45));
+    }
+
+    #[test]
+    fn rejects_malformed_duration() {
+        assert_eq!(parse_duration("not a duration"), None);
+        assert_eq!(parse_duration("1:2:3:4"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+}
@@ -1,14 +1,17 @@
-use std::cmp::min;
 use std::collections::HashMap;
 
 use std::thread;
 use std::sync::mpsc;
 use std::time::Duration;
 
-use pancurses::{Window, newwin, Input, Attribute};
+use pancurses::{Window, newwin, Input};
+use tts::Tts;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 use crate::config::Config;
 use crate::keymap::{Keybindings, UserAction};
 use crate::types::*;
+use crate::player::{format_elapsed, PlayerEvent};
 use super::MainMessage;
 
 /// Enum used for communicating back to the main controller after user
@@ -19,6 +22,14 @@ use super::MainMessage;
 pub enum UiMsg {
     AddFeed(String),
     Play(usize, usize),
+    PlayAll(usize),
+    Enqueue(usize, usize),
+    Pause,
+    Resume,
+    SeekForward,
+    SeekBack,
+    VolumeUp,
+    VolumeDown,
     MarkPlayed(usize, usize, bool),
     MarkAllPlayed(usize, bool),
     Sync(usize),
@@ -29,6 +40,18 @@ pub enum UiMsg {
     Noop,
 }
 
+/// Tracks what is currently playing, for the persistent now-playing
+/// status line at the bottom of the screen. Updated from `PlayerEvent`s
+/// pushed in from the playback thread.
+#[derive(Debug, Clone)]
+struct NowPlaying {
+    title: String,
+    elapsed: Duration,
+    total: Option<Duration>,
+    paused: bool,
+    up_next: Option<String>,
+}
+
 /// Simple enum to identify which menu is currently active.
 #[derive(Debug)]
 enum ActiveMenu {
@@ -49,17 +72,23 @@ pub struct UI<'a> {
     colors: Colors,
     podcast_menu: Menu<Podcast>,
     episode_menu: Menu<Episode>,
+    details: Details,
     active_menu: ActiveMenu,
     welcome_win: Option<Window>,
+    now_playing: Option<NowPlaying>,
+    speech_enabled: bool,
+    tts: Option<Tts>,
+    last_search: String,
 }
 
 impl<'a> UI<'a> {
     /// Spawns a UI object in a new thread, with message channels to send
     /// and receive messages
-    pub fn spawn(config: Config, items: LockVec<Podcast>, rx_from_main: mpsc::Receiver<MainMessage>, tx_to_main: mpsc::Sender<Message>) -> thread::JoinHandle<()> {
+    pub fn spawn(config: Config, items: LockVec<Podcast>, rx_from_main: mpsc::Receiver<MainMessage>, tx_to_main: mpsc::Sender<Message>, rx_player: mpsc::Receiver<PlayerEvent>) -> thread::JoinHandle<()> {
         return thread::spawn(move || {
             let mut ui = UI::new(&config, &items);
             let mut message_iter = rx_from_main.try_iter();
+            let mut player_iter = rx_player.try_iter();
             // on each loop, we check for user input, then we process
             // any messages from the main thread
             loop {
@@ -68,6 +97,10 @@ impl<'a> UI<'a> {
                     input => tx_to_main.send(Message::Ui(input)).unwrap(),
                 }
 
+                if let Some(event) = player_iter.next() {
+                    ui.handle_player_event(event);
+                }
+
                 if let Some(message) = message_iter.next() {
                     match message {
                         MainMessage::UiUpdateMenus => ui.update_menus(),
@@ -75,7 +108,10 @@ impl<'a> UI<'a> {
                         MainMessage::UiTearDown => {
                             ui.tear_down();
                             break;
-                        }
+                        },
+                        // other MainMessage variants (e.g. MPRIS updates)
+                        // are consumed by their own subsystem thread
+                        _ => (),
                     }
                 }
 
@@ -102,12 +138,13 @@ impl<'a> UI<'a> {
         stdscr.nodelay(true);  // getch() will not wait for user input
 
         // set colors
-        let colors = set_colors();
+        let colors = set_colors(&config.theme);
 
         let (n_row, n_col) = stdscr.get_max_yx();
 
-        let pod_col = n_col / 2;
-        let ep_col = n_col - pod_col + 1;
+        let pod_col = n_col / 3;
+        let ep_col = n_col / 3;
+        let det_col = n_col - pod_col - ep_col + 2;
 
         let podcast_menu_win = newwin(n_row - 1, pod_col, 0, 0);
         let mut podcast_menu = Menu {
@@ -120,6 +157,10 @@ impl<'a> UI<'a> {
             n_col: pod_col - 5,  // 2 for border, 2 for margins
             top_row: 0,
             selected: 0,
+            filter: None,
+            filtered_order: Vec::new(),
+            show_footer: true,
+            description_rows: config.description_rows,
         };
 
         stdscr.noutrefresh();
@@ -144,10 +185,26 @@ impl<'a> UI<'a> {
                                 // this needs an extra 1, but it works
             top_row: 0,
             selected: 0,
+            filter: None,
+            filtered_order: Vec::new(),
+            show_footer: true,
+            description_rows: config.description_rows,
         };
         episode_menu.init();
         episode_menu.window.noutrefresh();
 
+        let details_win = newwin(n_row - 1, det_col, 0, pod_col + ep_col - 2);
+        let mut details = Details {
+            window: details_win,
+            colors: colors.clone(),
+            n_row: n_row - 3,
+            n_col: det_col - 5,
+            top_row: 0,
+            lines: Vec::new(),
+        };
+        details.set_episode(episode_menu.items.borrow().get(0));
+        details.window.noutrefresh();
+
         // welcome screen if user does not have any podcasts yet
         let welcome_win = if items.borrow().len() == 0 {
             Some(UI::make_welcome_win(&config.keybindings, n_row, n_col))
@@ -165,9 +222,140 @@ impl<'a> UI<'a> {
             colors: colors,
             podcast_menu: podcast_menu,
             episode_menu: episode_menu,
+            details: details,
             active_menu: ActiveMenu::PodcastMenu,
             welcome_win: welcome_win,
+            now_playing: None,
+            speech_enabled: config.speech_enabled,
+            tts: Tts::default().ok(),
+            last_search: String::new(),
+        };
+    }
+
+    /// Speaks `text` aloud via the screen-reader backend, interrupting
+    /// any utterance that is still in progress. Does nothing if speech
+    /// is disabled or no speech backend was available at startup.
+    fn speak(&mut self, text: String) {
+        if self.speech_enabled {
+            if let Some(tts) = &mut self.tts {
+                let _ = tts.speak(text, true);
+            }
+        }
+    }
+
+    /// Refreshes the details pane with the currently selected episode.
+    /// Called whenever the episode selection, or the episode list itself
+    /// (e.g. after changing which podcast is selected), changes.
+    fn update_details(&mut self) {
+        let index = self.episode_menu.current_index() as usize;
+        let borrowed = self.episode_menu.items.borrow();
+        self.details.set_episode(borrowed.get(index));
+    }
+
+    /// Announces the currently selected podcast or episode, depending on
+    /// which menu is active, for screen-reader users.
+    fn announce_selection(&mut self) {
+        let text = match self.active_menu {
+            ActiveMenu::PodcastMenu => {
+                let index = self.podcast_menu.current_index() as usize;
+                self.podcast_menu.items.borrow().get(index).map(|pod| {
+                    let unplayed = pod.episodes.borrow().iter()
+                        .fold(0, |acc, ep| acc + (!ep.is_played() as i32));
+                    format!("podcast: {}, {} unplayed", pod.title, unplayed)
+                })
+            },
+            ActiveMenu::EpisodeMenu => {
+                let index = self.episode_menu.current_index() as usize;
+                self.episode_menu.items.borrow().get(index).map(|ep| {
+                    let status = if ep.is_played() { "played" } else { "unplayed" };
+                    format!("episode: {}, {}", ep.title, status)
+                })
+            },
         };
+        if let Some(text) = text {
+            self.speak(text);
+        }
+    }
+
+    /// Applies a `PlayerEvent` from the playback thread to the
+    /// now-playing state, then redraws the persistent status line. This
+    /// is what replaces the transient `spawn_msg_win` for reporting
+    /// what's currently playing.
+    pub fn handle_player_event(&mut self, event: PlayerEvent) {
+        match event {
+            PlayerEvent::Playing { title, elapsed, total } => {
+                self.now_playing = Some(NowPlaying { title, elapsed, total, paused: false, up_next: None });
+            },
+            PlayerEvent::Loading { title } => {
+                if let Some(np) = &mut self.now_playing {
+                    np.up_next = Some(title);
+                }
+            },
+            PlayerEvent::EndOfTrack { .. } => (),
+            PlayerEvent::Paused => {
+                if let Some(np) = &mut self.now_playing {
+                    np.paused = true;
+                }
+            },
+            PlayerEvent::Progress { elapsed } => {
+                if let Some(np) = &mut self.now_playing {
+                    np.elapsed = elapsed;
+                    np.paused = false;
+                }
+            },
+            PlayerEvent::Finished => {
+                self.now_playing = None;
+            },
+            PlayerEvent::Error(msg) => {
+                self.now_playing = None;
+                self.spawn_msg_win(msg, 5000, true);
+            },
+        }
+        self.draw_status_line();
+    }
+
+    /// Draws the persistent bottom status line showing the title,
+    /// elapsed/total time, and a progress bar for whatever is currently
+    /// playing. When nothing is playing, the line is simply cleared.
+    fn draw_status_line(&self) {
+        let status_win = newwin(1, self.n_col, self.n_row - 1, 0);
+        status_win.erase();
+
+        if let Some(np) = &self.now_playing {
+            let elapsed_str = format_elapsed(np.elapsed);
+            let state = if np.paused { "paused" } else { "playing" };
+            let prefix = match np.total {
+                Some(total) => format!("[{}] {} ({}/{}) ", state, np.title, elapsed_str, format_elapsed(total)),
+                None => format!("[{}] {} ({}) ", state, np.title, elapsed_str),
+            };
+            status_win.mvaddstr(0, 0, &prefix);
+
+            if let Some(next_title) = &np.up_next {
+                let suffix = format!(" | up next: {}", next_title);
+                let suffix_start = self.n_col - suffix.len() as i32;
+                if suffix_start > prefix.len() as i32 {
+                    status_win.mvaddstr(0, suffix_start, &suffix);
+                }
+            }
+
+            if let Some(total) = np.total {
+                let bar_start = prefix.len() as i32;
+                let bar_width = self.n_col - bar_start - 2;
+                if bar_width > 0 {
+                    let pct = (np.elapsed.as_secs_f64() / total.as_secs_f64()).min(1.0);
+                    let filled = (pct * bar_width as f64) as i32;
+                    status_win.mvaddstr(0, bar_start, "[");
+                    for i in 0..bar_width {
+                        let ch = if i < filled { "=" } else { " " };
+                        status_win.mvaddstr(0, bar_start + 1 + i, ch);
+                    }
+                    status_win.mvaddstr(0, bar_start + 1 + bar_width, "]");
+                }
+            }
+        }
+
+        status_win.refresh();
+        status_win.delwin();
     }
 
     /// Waits for user input and, where necessary, provides UiMessages
@@ -186,10 +374,12 @@ impl<'a> UI<'a> {
                 self.n_row = n_row;
                 self.n_col = n_col;
 
-                let pod_col = n_col / 2;
-                let ep_col = n_col - pod_col;
+                let pod_col = n_col / 3;
+                let ep_col = n_col / 3;
+                let det_col = n_col - pod_col - ep_col + 2;
                 self.podcast_menu.resize(n_row-3, pod_col-5);
                 self.episode_menu.resize(n_row-3, ep_col-5);
+                self.details.resize(n_row-3, det_col-5);
 
                 // apparently pancurses does not implement `wresize()`
                 // from ncurses, so instead we create an entirely new
@@ -201,10 +391,15 @@ impl<'a> UI<'a> {
                 let ep_oldwin = std::mem::replace(
                     &mut self.episode_menu.window,
                     newwin(n_row-1, ep_col, 0, pod_col-1));
+                let det_oldwin = std::mem::replace(
+                    &mut self.details.window,
+                    newwin(n_row-1, det_col, 0, pod_col+ep_col-2));
                 pod_oldwin.delwin();
                 ep_oldwin.delwin();
+                det_oldwin.delwin();
                 self.stdscr.refresh();
                 self.update_menus();
+                self.update_details();
                 
                 match self.active_menu {
                     ActiveMenu::PodcastMenu => self.podcast_menu.activate(),
@@ -228,10 +423,8 @@ impl<'a> UI<'a> {
             Some(input) => {
                 let pod_len = self.podcast_menu.items.borrow().len();
                 let ep_len = self.episode_menu.items.borrow().len();
-                let current_pod_index = (self.podcast_menu.selected +
-                    self.podcast_menu.top_row) as usize;
-                let current_ep_index = (self.episode_menu.selected +
-                    self.episode_menu.top_row) as usize;
+                let current_pod_index = self.podcast_menu.current_index() as usize;
+                let current_ep_index = self.episode_menu.current_index() as usize;
 
                 // get rid of the "welcome" window once the podcast list
                 // is no longer empty
@@ -261,6 +454,8 @@ impl<'a> UI<'a> {
                                 }
                             },
                         }
+                        self.update_details();
+                        self.announce_selection();
                     },
 
                     Some(UserAction::Up) => {
@@ -283,6 +478,134 @@ impl<'a> UI<'a> {
                                 }
                             },
                         }
+                        self.update_details();
+                        self.announce_selection();
+                    },
+
+                    Some(UserAction::PageUp) => {
+                        match self.active_menu {
+                            ActiveMenu::PodcastMenu => {
+                                if pod_len > 0 {
+                                    self.podcast_menu.page_up();
+                                    self.episode_menu.top_row = 0;
+                                    self.episode_menu.selected = 0;
+                                    self.episode_menu.items = self.podcast_menu.get_episodes();
+                                    self.episode_menu.update_items();
+                                }
+                            },
+                            ActiveMenu::EpisodeMenu => {
+                                if ep_len > 0 {
+                                    self.episode_menu.page_up();
+                                }
+                            },
+                        }
+                        self.update_details();
+                        self.announce_selection();
+                    },
+
+                    Some(UserAction::PageDown) => {
+                        match self.active_menu {
+                            ActiveMenu::PodcastMenu => {
+                                if pod_len > 0 {
+                                    self.podcast_menu.page_down();
+                                    self.episode_menu.top_row = 0;
+                                    self.episode_menu.selected = 0;
+                                    self.episode_menu.items = self.podcast_menu.get_episodes();
+                                    self.episode_menu.update_items();
+                                }
+                            },
+                            ActiveMenu::EpisodeMenu => {
+                                if ep_len > 0 {
+                                    self.episode_menu.page_down();
+                                }
+                            },
+                        }
+                        self.update_details();
+                        self.announce_selection();
+                    },
+
+                    Some(UserAction::HalfPageUp) => {
+                        match self.active_menu {
+                            ActiveMenu::PodcastMenu => {
+                                if pod_len > 0 {
+                                    self.podcast_menu.half_page_up();
+                                    self.episode_menu.top_row = 0;
+                                    self.episode_menu.selected = 0;
+                                    self.episode_menu.items = self.podcast_menu.get_episodes();
+                                    self.episode_menu.update_items();
+                                }
+                            },
+                            ActiveMenu::EpisodeMenu => {
+                                if ep_len > 0 {
+                                    self.episode_menu.half_page_up();
+                                }
+                            },
+                        }
+                        self.update_details();
+                        self.announce_selection();
+                    },
+
+                    Some(UserAction::HalfPageDown) => {
+                        match self.active_menu {
+                            ActiveMenu::PodcastMenu => {
+                                if pod_len > 0 {
+                                    self.podcast_menu.half_page_down();
+                                    self.episode_menu.top_row = 0;
+                                    self.episode_menu.selected = 0;
+                                    self.episode_menu.items = self.podcast_menu.get_episodes();
+                                    self.episode_menu.update_items();
+                                }
+                            },
+                            ActiveMenu::EpisodeMenu => {
+                                if ep_len > 0 {
+                                    self.episode_menu.half_page_down();
+                                }
+                            },
+                        }
+                        self.update_details();
+                        self.announce_selection();
+                    },
+
+                    Some(UserAction::Home) => {
+                        match self.active_menu {
+                            ActiveMenu::PodcastMenu => {
+                                if pod_len > 0 {
+                                    self.podcast_menu.home();
+                                    self.episode_menu.top_row = 0;
+                                    self.episode_menu.selected = 0;
+                                    self.episode_menu.items = self.podcast_menu.get_episodes();
+                                    self.episode_menu.update_items();
+                                }
+                            },
+                            ActiveMenu::EpisodeMenu => {
+                                if ep_len > 0 {
+                                    self.episode_menu.home();
+                                }
+                            },
+                        }
+                        self.update_details();
+                        self.announce_selection();
+                    },
+
+                    Some(UserAction::End) => {
+                        match self.active_menu {
+                            ActiveMenu::PodcastMenu => {
+                                if pod_len > 0 {
+                                    self.podcast_menu.end();
+                                    self.episode_menu.top_row = 0;
+                                    self.episode_menu.selected = 0;
+                                    self.episode_menu.items = self.podcast_menu.get_episodes();
+                                    self.episode_menu.update_items();
+                                }
+                            },
+                            ActiveMenu::EpisodeMenu => {
+                                if ep_len > 0 {
+                                    self.episode_menu.end();
+                                }
+                            },
+                        }
+                        self.update_details();
+                        self.announce_selection();
                     },
 
                     Some(UserAction::Left) => {
@@ -296,6 +619,8 @@ impl<'a> UI<'a> {
                                 },
                             }
                         }
+                        self.update_details();
+                        self.announce_selection();
                     },
 
                     Some(UserAction::Right) => {
@@ -309,6 +634,14 @@ impl<'a> UI<'a> {
                                 ActiveMenu::EpisodeMenu => (),
                             }
                         }
+                        self.update_details();
+                        self.announce_selection();
+                    },
+
+                    Some(UserAction::ToggleSpeech) => {
+                        self.speech_enabled = !self.speech_enabled;
+                        let state = if self.speech_enabled { "on" } else { "off" };
+                        self.speak(format!("speech {}", state));
                     },
 
                     Some(UserAction::AddFeed) => {
@@ -333,6 +666,37 @@ impl<'a> UI<'a> {
                             return UiMsg::Play(current_pod_index, current_ep_index);
                         }
                     },
+                    Some(UserAction::PlayAll) => {
+                        if pod_len > 0 {
+                            return UiMsg::PlayAll(current_pod_index);
+                        }
+                    },
+                    Some(UserAction::Enqueue) => {
+                        if ep_len > 0 {
+                            return UiMsg::Enqueue(current_pod_index, current_ep_index);
+                        }
+                    },
+                    Some(UserAction::Pause) => {
+                        if self.now_playing.is_some() {
+                            return UiMsg::Pause;
+                        }
+                    },
+                    Some(UserAction::SeekForward) => {
+                        if self.now_playing.is_some() {
+                            return UiMsg::SeekForward;
+                        }
+                    },
+                    Some(UserAction::SeekBack) => {
+                        if self.now_playing.is_some() {
+                            return UiMsg::SeekBack;
+                        }
+                    },
+                    Some(UserAction::VolumeUp) => {
+                        return UiMsg::VolumeUp;
+                    },
+                    Some(UserAction::VolumeDown) => {
+                        return UiMsg::VolumeDown;
+                    },
                     Some(UserAction::MarkPlayed) => {
                         match self.active_menu {
                             ActiveMenu::PodcastMenu => (),
@@ -413,7 +777,29 @@ impl<'a> UI<'a> {
                     Some(UserAction::DeleteAll) => {},
                     Some(UserAction::Remove) => {},
                     Some(UserAction::RemoveAll) => {},
-                    Some(UserAction::Search) => {},
+                    Some(UserAction::Search) => {
+                        if pod_len > 0 {
+                            self.search_active_menu();
+                        }
+                    },
+                    Some(UserAction::SearchNext) => {
+                        self.search_cycle(false);
+                    },
+                    Some(UserAction::SearchPrev) => {
+                        self.search_cycle(true);
+                    },
+                    Some(UserAction::Filter) => {
+                        if pod_len > 0 {
+                            self.filter_active_menu();
+                        }
+                    },
+
+                    Some(UserAction::ScrollDetailsDown) => {
+                        self.details.scroll(1);
+                    },
+                    Some(UserAction::ScrollDetailsUp) => {
+                        self.details.scroll(-1);
+                    },
 
                     Some(UserAction::Quit) => {
                         return UiMsg::Quit;
@@ -431,21 +817,72 @@ impl<'a> UI<'a> {
     /// for the user at the beginning of the input line. This returns the
     /// user's input; if the user cancels their input, the String will be
     /// empty.
+    ///
+    /// The input line is edited in terms of Unicode grapheme clusters
+    /// rather than raw columns, so pasting accented or CJK text moves
+    /// the cursor and deletes characters the way the user actually sees
+    /// them on screen, instead of splitting a multibyte character in
+    /// half. The visible window scrolls horizontally once the input
+    /// grows wider than the available columns.
     pub fn spawn_input_win(&self, prefix: &str) -> String {
         let input_win = newwin(1, self.n_col, self.n_row-1, 0);
         // input_win.overlay(&self.podcast_menu.window);
-        input_win.mv(self.n_row-1, 0);
-        input_win.addstr(&prefix);
         input_win.keypad(true);
-        input_win.refresh();
         pancurses::curs_set(2);
-        
-        let mut inputs = String::new();
+
+        let prefix_width = UnicodeWidthStr::width(prefix) as i32;
+        let avail_cols = self.n_col - prefix_width;
+
+        // the input is held as a vector of grapheme clusters rather than
+        // a flat String, so cursor movement and deletion always act on
+        // whole clusters
+        let mut graphemes: Vec<String> = Vec::new();
+        let mut cursor = 0;  // index into `graphemes`, 0..=graphemes.len()
+        let mut view_start = 0;  // index of first visible grapheme
         let mut cancelled = false;
 
-        let min_x = prefix.len() as i32;
-        let mut current_x = prefix.len() as i32;
-        let mut cursor_x = prefix.len() as i32;
+        let redraw = |win: &Window, graphemes: &[String], cursor: usize, view_start: &mut usize| {
+            // scroll the view so the cursor stays visible; the left-
+            // ward clamp must run before the `graphemes[*view_start..
+            // cursor]` slice below, or moving the cursor left past the
+            // current view_start would slice with start > end and panic
+            if cursor < *view_start {
+                *view_start = cursor;
+            }
+            loop {
+                let width_before_cursor: usize = graphemes[*view_start..cursor]
+                    .iter()
+                    .map(|g| UnicodeWidthStr::width(g.as_str()))
+                    .sum();
+                if width_before_cursor as i32 > avail_cols {
+                    *view_start += 1;
+                } else {
+                    break;
+                }
+            }
+
+            win.mv(0, 0);
+            win.clrtoeol();
+            win.addstr(&prefix);
+
+            let mut cursor_x = prefix_width;
+            let mut width_used = 0;
+            for (i, g) in graphemes.iter().enumerate().skip(*view_start) {
+                let w = UnicodeWidthStr::width(g.as_str()) as i32;
+                if width_used + w > avail_cols {
+                    break;
+                }
+                if i < cursor {
+                    cursor_x += w;
+                }
+                win.addstr(g);
+                width_used += w;
+            }
+            win.mv(0, cursor_x);
+            win.refresh();
+        };
+
+        redraw(&input_win, &graphemes, cursor, &mut view_start);
         loop {
             match input_win.getch() {
                 // Cancel input
@@ -461,88 +898,378 @@ impl<'a> UI<'a> {
                 },
                 Some(Input::KeyBackspace) |
                 Some(Input::Character('\u{7f}')) => {
-                    if current_x > min_x {
-                        current_x -= 1;
-                        cursor_x -= 1;
-                        let _ = inputs.remove((cursor_x as usize) - prefix.len());
-                        input_win.mv(0, cursor_x);
-                        input_win.delch();
+                    if cursor > 0 {
+                        cursor -= 1;
+                        graphemes.remove(cursor);
                     }
                 },
                 Some(Input::KeyDC) => {
-                    if cursor_x < current_x {
-                        let _ = inputs.remove((cursor_x as usize) - prefix.len());
-                        input_win.delch();
+                    if cursor < graphemes.len() {
+                        graphemes.remove(cursor);
                     }
                 },
                 Some(Input::KeyLeft) => {
-                    if cursor_x > min_x {
-                        cursor_x -= 1;
-                        input_win.mv(0, cursor_x);
+                    if cursor > 0 {
+                        cursor -= 1;
                     }
                 },
                 Some(Input::KeyRight) => {
-                    if cursor_x < current_x {
-                        cursor_x += 1;
-                        input_win.mv(0, cursor_x);
+                    if cursor < graphemes.len() {
+                        cursor += 1;
                     }
                 },
                 Some(Input::Character(c)) => {
-                    current_x += 1;
-                    cursor_x += 1;
-                    input_win.insch(c);
-                    input_win.mv(0, cursor_x);
-                    inputs.push(c);
+                    let mut buf = [0u8; 4];
+                    let s = c.encode_utf8(&mut buf);
+                    // a single `char` may combine with the previous
+                    // grapheme (e.g. a combining accent); re-segment the
+                    // two together to decide whether to merge or insert
+                    if cursor > 0 {
+                        let combined = format!("{}{}", graphemes[cursor - 1], s);
+                        let mut combined_graphemes = UnicodeSegmentation::graphemes(combined.as_str(), true);
+                        if combined_graphemes.clone().count() == 1 {
+                            graphemes[cursor - 1] = combined_graphemes.next().unwrap().to_string();
+                            redraw(&input_win, &graphemes, cursor, &mut view_start);
+                            continue;
+                        }
+                    }
+                    graphemes.insert(cursor, s.to_string());
+                    cursor += 1;
                 },
                 Some(_) => (),
                 None => (),
             }
-            input_win.refresh();
+            redraw(&input_win, &graphemes, cursor, &mut view_start);
         }
 
         pancurses::curs_set(0);
-        input_win.deleteln();
+        input_win.erase();
         input_win.refresh();
         input_win.delwin();
 
         if cancelled {
             return String::from("");
         }
-        return inputs;
+        return graphemes.join("");
     }
 
-    /// Adds a one-line pancurses window to the bottom of the screen for
-    /// displaying messages to the user. `duration` indicates how long
-    /// (in milliseconds) this message will remain on screen. Useful for
-    /// presenting error messages, among other things.
-    pub fn spawn_msg_win(&self, message: String, duration: u64, error: bool) {
-        let n_col = self.n_col;
-        let begy = self.n_row - 1;
-        let err_color = self.colors.get(ColorType::Error);
-        thread::spawn(move || {
-            let msg_win = newwin(1, n_col, begy, 0);
-            msg_win.mv(begy, 0);
-            msg_win.attrset(pancurses::A_NORMAL);
-            msg_win.addstr(message);
+    /// Opens a "Search: " prompt and incrementally filters the currently
+    /// active menu as the query is edited: each keystroke re-runs a
+    /// case-insensitive substring search over the visible titles and
+    /// jumps the selection to the first match. Escape restores the
+    /// selection from before the search began; Enter commits the query
+    /// so `n`/`N` (`search_cycle`) can step through the remaining
+    /// matches afterwards.
+    fn search_active_menu(&mut self) {
+        let (prior_top, prior_selected) = match self.active_menu {
+            ActiveMenu::PodcastMenu => (self.podcast_menu.top_row, self.podcast_menu.selected),
+            ActiveMenu::EpisodeMenu => (self.episode_menu.top_row, self.episode_menu.selected),
+        };
 
-            if error {
-                msg_win.mvchgat(0, 0, -1, pancurses::A_BOLD,
-                    err_color);
+        let prefix = "Search: ";
+        let input_win = newwin(1, self.n_col, self.n_row - 1, 0);
+        input_win.keypad(true);
+        pancurses::curs_set(2);
+
+        let mut graphemes: Vec<String> = Vec::new();
+        let mut cursor = 0;
+        let mut cancelled = false;
+
+        loop {
+            input_win.erase();
+            input_win.mv(0, 0);
+            input_win.addstr(prefix);
+            input_win.addstr(graphemes.join(""));
+            input_win.refresh();
+
+            match input_win.getch() {
+                Some(Input::KeyExit) |
+                Some(Input::Character('\u{1b}')) => {
+                    cancelled = true;
+                    break;
+                },
+                Some(Input::KeyEnter) |
+                Some(Input::Character('\n')) => break,
+                Some(Input::KeyBackspace) |
+                Some(Input::Character('\u{7f}')) => {
+                    if cursor > 0 {
+                        cursor -= 1;
+                        graphemes.remove(cursor);
+                    }
+                },
+                Some(Input::KeyLeft) => {
+                    if cursor > 0 {
+                        cursor -= 1;
+                    }
+                },
+                Some(Input::KeyRight) => {
+                    if cursor < graphemes.len() {
+                        cursor += 1;
+                    }
+                },
+                Some(Input::Character(c)) => {
+                    let mut buf = [0u8; 4];
+                    let s = c.encode_utf8(&mut buf);
+                    graphemes.insert(cursor, s.to_string());
+                    cursor += 1;
+                },
+                Some(_) => (),
+                None => (),
             }
-            msg_win.refresh();
 
-            // TODO: This probably should be some async function, but this
-            // works for now
-            // pancurses::napms(duration);
-            thread::sleep(Duration::from_millis(duration));
-            
-            msg_win.erase();
-            msg_win.refresh();
-            msg_win.delwin();
-        });
+            self.last_search = graphemes.join("");
+            self.jump_to_first_match(&self.last_search.clone());
+        }
+
+        pancurses::curs_set(0);
+        input_win.erase();
+        input_win.refresh();
+        input_win.delwin();
+
+        if cancelled {
+            match self.active_menu {
+                ActiveMenu::PodcastMenu => {
+                    self.podcast_menu.top_row = prior_top;
+                    self.podcast_menu.selected = prior_selected;
+                    self.podcast_menu.update_items();
+                    self.podcast_menu.activate();
+                },
+                ActiveMenu::EpisodeMenu => {
+                    self.episode_menu.top_row = prior_top;
+                    self.episode_menu.selected = prior_selected;
+                    self.episode_menu.update_items();
+                    self.episode_menu.activate();
+                },
+            }
+        }
     }
 
-    /// Forces the menus to check the list of podcasts/episodes again and
+    /// Jumps the active menu's selection to the first item (in displayed,
+    /// possibly filtered, order) whose title contains `query`,
+    /// case-insensitively. Does nothing if the query is empty or matches
+    /// nothing.
+    fn jump_to_first_match(&mut self, query: &str) {
+        if query.is_empty() {
+            return;
+        }
+        let query_lower = query.to_lowercase();
+        match self.active_menu {
+            ActiveMenu::PodcastMenu => {
+                let menu = &self.podcast_menu;
+                let found = (0..menu.display_len()).find(|&pos| {
+                    menu.items.borrow().get(menu.display_idx(pos) as usize)
+                        .map_or(false, |pod| pod.title.to_lowercase().contains(&query_lower))
+                });
+                if let Some(pos) = found {
+                    self.podcast_menu.jump_to(pos);
+                    self.podcast_menu.activate();
+                }
+            },
+            ActiveMenu::EpisodeMenu => {
+                let menu = &self.episode_menu;
+                let found = (0..menu.display_len()).find(|&pos| {
+                    menu.items.borrow().get(menu.display_idx(pos) as usize)
+                        .map_or(false, |ep| ep.title.to_lowercase().contains(&query_lower))
+                });
+                if let Some(pos) = found {
+                    self.episode_menu.jump_to(pos);
+                    self.episode_menu.activate();
+                }
+            },
+        }
+    }
+
+    /// Cycles the active menu's selection to the next (or, if `reverse`,
+    /// previous) item matching the last committed search query, wrapping
+    /// around the ends of the list. Does nothing if there is no active
+    /// search query.
+    fn search_cycle(&mut self, reverse: bool) {
+        if self.last_search.is_empty() {
+            return;
+        }
+        let query_lower = self.last_search.to_lowercase();
+        match self.active_menu {
+            ActiveMenu::PodcastMenu => {
+                let len = self.podcast_menu.display_len() as usize;
+                if len == 0 {
+                    return;
+                }
+                let current = (self.podcast_menu.selected + self.podcast_menu.top_row) as usize;
+                for step in 1..=len {
+                    let idx = if reverse {
+                        (current + len - step) % len
+                    } else {
+                        (current + step) % len
+                    };
+                    let item_idx = self.podcast_menu.display_idx(idx as i32) as usize;
+                    let is_match = self.podcast_menu.items.borrow()
+                        .get(item_idx).unwrap().title.to_lowercase().contains(&query_lower);
+                    if is_match {
+                        self.podcast_menu.jump_to(idx as i32);
+                        self.podcast_menu.activate();
+                        break;
+                    }
+                }
+            },
+            ActiveMenu::EpisodeMenu => {
+                let len = self.episode_menu.display_len() as usize;
+                if len == 0 {
+                    return;
+                }
+                let current = (self.episode_menu.selected + self.episode_menu.top_row) as usize;
+                for step in 1..=len {
+                    let idx = if reverse {
+                        (current + len - step) % len
+                    } else {
+                        (current + step) % len
+                    };
+                    let item_idx = self.episode_menu.display_idx(idx as i32) as usize;
+                    let is_match = self.episode_menu.items.borrow()
+                        .get(item_idx).unwrap().title.to_lowercase().contains(&query_lower);
+                    if is_match {
+                        self.episode_menu.jump_to(idx as i32);
+                        self.episode_menu.activate();
+                        break;
+                    }
+                }
+            },
+        }
+    }
+
+    /// Opens a "Filter: " prompt and narrows the active menu down to
+    /// items matching a fuzzy query as it's typed: each keystroke
+    /// rescores every item and redraws the menu with only the matches
+    /// visible, best match first. Backspacing the query down to empty
+    /// clears the filter. Escape restores whatever filter (or lack of
+    /// one) was active before the prompt was opened; Enter leaves the
+    /// current filter in place.
+    fn filter_active_menu(&mut self) {
+        let prior_filter = match self.active_menu {
+            ActiveMenu::PodcastMenu => self.podcast_menu.filter.clone(),
+            ActiveMenu::EpisodeMenu => self.episode_menu.filter.clone(),
+        };
+
+        let prefix = "Filter: ";
+        let input_win = newwin(1, self.n_col, self.n_row - 1, 0);
+        input_win.keypad(true);
+        pancurses::curs_set(2);
+
+        let mut graphemes: Vec<String> = prior_filter.as_deref()
+            .unwrap_or("")
+            .chars()
+            .map(|c| c.to_string())
+            .collect();
+        let mut cursor = graphemes.len();
+        let mut cancelled = false;
+
+        loop {
+            input_win.erase();
+            input_win.mv(0, 0);
+            input_win.addstr(prefix);
+            input_win.addstr(graphemes.join(""));
+            input_win.refresh();
+
+            match input_win.getch() {
+                Some(Input::KeyExit) |
+                Some(Input::Character('\u{1b}')) => {
+                    cancelled = true;
+                    break;
+                },
+                Some(Input::KeyEnter) |
+                Some(Input::Character('\n')) => break,
+                Some(Input::KeyBackspace) |
+                Some(Input::Character('\u{7f}')) => {
+                    if cursor > 0 {
+                        cursor -= 1;
+                        graphemes.remove(cursor);
+                    }
+                },
+                Some(Input::KeyLeft) => {
+                    if cursor > 0 {
+                        cursor -= 1;
+                    }
+                },
+                Some(Input::KeyRight) => {
+                    if cursor < graphemes.len() {
+                        cursor += 1;
+                    }
+                },
+                Some(Input::Character(c)) => {
+                    let mut buf = [0u8; 4];
+                    let s = c.encode_utf8(&mut buf);
+                    graphemes.insert(cursor, s.to_string());
+                    cursor += 1;
+                },
+                Some(_) => (),
+                None => (),
+            }
+
+            let query = graphemes.join("");
+            match self.active_menu {
+                ActiveMenu::PodcastMenu => self.podcast_menu.set_filter(&query),
+                ActiveMenu::EpisodeMenu => self.episode_menu.set_filter(&query),
+            }
+        }
+
+        pancurses::curs_set(0);
+        input_win.erase();
+        input_win.refresh();
+        input_win.delwin();
+
+        if cancelled {
+            match self.active_menu {
+                ActiveMenu::PodcastMenu => match &prior_filter {
+                    Some(query) => self.podcast_menu.set_filter(query),
+                    None => self.podcast_menu.clear_filter(),
+                },
+                ActiveMenu::EpisodeMenu => match &prior_filter {
+                    Some(query) => self.episode_menu.set_filter(query),
+                    None => self.episode_menu.clear_filter(),
+                },
+            }
+        }
+
+        match self.active_menu {
+            ActiveMenu::PodcastMenu => self.podcast_menu.activate(),
+            ActiveMenu::EpisodeMenu => self.episode_menu.activate(),
+        }
+    }
+
+    /// Adds a one-line pancurses window to the bottom of the screen for
+    /// displaying messages to the user. `duration` indicates how long
+    /// (in milliseconds) this message will remain on screen. Useful for
+    /// presenting error messages, among other things. Also announced via
+    /// the screen-reader backend, if speech is enabled.
+    pub fn spawn_msg_win(&mut self, message: String, duration: u64, error: bool) {
+        self.speak(message.clone());
+
+        let n_col = self.n_col;
+        let begy = self.n_row - 1;
+        let err_color = self.colors.get(ColorType::Error);
+        thread::spawn(move || {
+            let msg_win = newwin(1, n_col, begy, 0);
+            msg_win.mv(begy, 0);
+            msg_win.attrset(pancurses::A_NORMAL);
+            msg_win.addstr(message);
+
+            if error {
+                msg_win.mvchgat(0, 0, -1, pancurses::A_BOLD,
+                    err_color);
+            }
+            msg_win.refresh();
+
+            // TODO: This probably should be some async function, but this
+            // works for now
+            // pancurses::napms(duration);
+            thread::sleep(Duration::from_millis(duration));
+            
+            msg_win.erase();
+            msg_win.refresh();
+            msg_win.delwin();
+        });
+    }
+
+    /// Forces the menus to check the list of podcasts/episodes again and
     /// update.
     pub fn update_menus(&mut self) {
         self.podcast_menu.update_items();
@@ -633,6 +1360,18 @@ impl<'a> UI<'a> {
 /// * `selected` indicates which item on screen is currently highlighted.
 ///   It is calculated relative to the screen itself, i.e., a value between
 ///   0 and (n_row - 1)
+/// * `filter`, when set, narrows the menu down to the items matching a
+///   fuzzy query; `filtered_order` then holds those items' indices into
+///   `items`, in score order, and `top_row`/`selected` address positions
+///   in that narrowed list rather than `items` directly
+/// * `show_footer` reserves the window's last row for a counts/position
+///   summary (e.g. "12/240 · 38 unplayed · ▼ 5%"); `viewport_rows()`
+///   is the item-list height after that reservation
+/// * `description_rows`, when non-zero, reserves that many additional
+///   rows (plus one for a separator) below the item list for a preview
+///   of the selected item's `Menuable::preview_text()`; 0 disables it.
+///   Set from `config.description_rows`, so a narrow terminal can turn
+///   the pane off entirely
 #[derive(Debug)]
 pub struct Menu<T>
     where T: Clone + Menuable {
@@ -645,6 +1384,53 @@ pub struct Menu<T>
     n_col: i32,
     top_row: i32,  // top row of text shown in window
     selected: i32,  // which line of text is highlighted
+    filter: Option<String>,  // active fuzzy-filter query, if any
+    filtered_order: Vec<i32>,  // indices into `items` matching `filter`, in score order
+    show_footer: bool,  // whether to reserve the last row for a counts/position footer
+    description_rows: usize,  // rows reserved below the list for a preview pane; 0 disables it
+}
+
+/// Scores `target` against `query` as a fuzzy subsequence match: every
+/// character of `query` must appear in `target`, in order, but not
+/// necessarily contiguously. Matching, case-insensitively, returns a
+/// score that rewards matches at the start of a word (+16) and matches
+/// that continue directly off the previous one (+8), and penalizes
+/// characters skipped over between matches (-1). Returns `None` if
+/// `query` is not a subsequence of `target`.
+fn fuzzy_score(target: &str, query: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let target_lower = target.to_lowercase();
+    let query_lower = query.to_lowercase();
+    let target_chars: Vec<char> = target_lower.chars().collect();
+    let query_chars: Vec<char> = query_lower.chars().collect();
+
+    let mut score = 0;
+    let mut qi = 0;
+    let mut prev_matched_at: Option<usize> = None;
+    for (ti, &tc) in target_chars.iter().enumerate() {
+        if qi >= query_chars.len() {
+            break;
+        }
+        if tc == query_chars[qi] {
+            let at_word_start = ti == 0 || !target_chars[ti - 1].is_alphanumeric();
+            if at_word_start {
+                score += 16;
+            }
+            if prev_matched_at == Some(ti.wrapping_sub(1)) {
+                score += 8;
+            }
+            prev_matched_at = Some(ti);
+            qi += 1;
+        } else if qi > 0 {
+            score -= 1;
+        }
+    }
+    if qi < query_chars.len() {
+        return None;
+    }
+    return Some(score);
 }
 
 impl<T: Clone + Menuable> Menu<T> {
@@ -655,7 +1441,7 @@ impl<T: Clone + Menuable> Menu<T> {
         self.update_items();
     }
 
-    /// Draws a border around the window.
+    /// Draws a border around the window, in the theme's neutral `Gray`.
     fn draw_border(&self) {
         let top_left;
         let bot_left;
@@ -669,6 +1455,7 @@ impl<T: Clone + Menuable> Menu<T> {
                 bot_left = pancurses::ACS_BTEE();
             }
         }
+        self.window.color_set(self.colors.get(ColorType::Gray));
         self.window.border(
             pancurses::ACS_VLINE(),
             pancurses::ACS_VLINE(),
@@ -678,17 +1465,33 @@ impl<T: Clone + Menuable> Menu<T> {
             pancurses::ACS_URCORNER(),
             bot_left,
             pancurses::ACS_LRCORNER());
+        self.window.color_set(self.colors.get(ColorType::Normal));
 
         self.window.mvaddstr(0, 2, self.title.clone());
     }
 
     /// Prints or reprints the list of visible items to the pancurses
-    /// window and refreshes it.
+    /// window and refreshes it. On a terminal too short to fit even one
+    /// item row (`viewport_rows() < 1`), a truncated
+    /// "[terminal too small]" placeholder is shown instead.
     fn update_items(&mut self) {
         self.window.erase();
         self.draw_border();
 
-        if self.items.borrow().is_empty() {
+        if self.viewport_rows() < 1 {
+            self.top_row = 0;
+            self.selected = -1;
+            if self.n_row - self.footer_rows() >= 1 {
+                let msg = "[terminal too small]";
+                let cols = self.n_col.max(0) as usize;
+                self.window.mv(self.abs_y(0), self.abs_x(0));
+                self.window.addstr(msg.slice(..msg.len().min(cols)));
+            }
+            self.window.refresh();
+            return;
+        }
+
+        if self.display_len() == 0 {
             self.selected = -1;
         } else {
             if self.selected == -1 {
@@ -696,103 +1499,263 @@ impl<T: Clone + Menuable> Menu<T> {
             }
 
             // for visible rows, print strings from list
-            for i in 0..self.n_row {
-                let item_idx = (self.top_row + i) as usize;
+            let viewport_rows = self.viewport_rows();
+            for i in 0..viewport_rows {
+                let pos = self.top_row + i;
+                if pos >= self.display_len() {
+                    break;
+                }
+                let item_idx = self.display_idx(pos) as usize;
                 if let Some(elem) = self.items.borrow().get(item_idx) {
-                    // look for any unplayed episodes
-                    let unplayed = !elem.is_played();
+                    let (color, bold) = elem.status_style();
+                    let attr = if bold { pancurses::A_BOLD } else { pancurses::A_NORMAL };
                     self.window.mv(self.abs_y(i), self.abs_x(0));
-                    if unplayed {
-                        self.window.attron(Attribute::Bold);
-                    }
                     self.window.addstr(elem.get_title(self.n_col as usize));
-                    if unplayed {
-                        self.window.attroff(Attribute::Bold);
-                    }
+                    self.window.mvchgat(self.abs_y(i), self.abs_x(0),
+                        self.n_col, attr, self.colors.get(color));
                 } else {
                     break;
                 }
             }
         }
+        self.render_description();
+        self.print_footer();
         self.window.refresh();
     }
 
+    /// Number of rows the description pane reserves, including its
+    /// separator line: `description_rows + 1` when the pane is enabled,
+    /// 0 when `description_rows` is 0.
+    fn description_reserved_rows(&self) -> i32 {
+        return if self.description_rows > 0 {
+            self.description_rows as i32 + 1
+        } else {
+            0
+        };
+    }
+
+    /// Draws a separator line and the selected item's word-wrapped
+    /// `Menuable::preview_text()` in the rows reserved by
+    /// `description_rows`. A no-op if the pane is disabled or nothing
+    /// is selected.
+    fn render_description(&self) {
+        if self.description_rows == 0 {
+            return;
+        }
+        let separator_row = self.viewport_rows();
+        self.window.mv(self.abs_y(separator_row), self.abs_x(0));
+        self.window.addstr("-".repeat(self.n_col as usize));
+
+        if self.selected == -1 {
+            return;
+        }
+        let abs_idx = self.display_idx(self.top_row + self.selected) as usize;
+        let text = match self.items.borrow().get(abs_idx) {
+            Some(item) => item.preview_text().map(|s| s.to_string()),
+            None => None,
+        };
+        if let Some(text) = text {
+            for (i, line) in wrap_text(&text, self.n_col as usize)
+                .iter()
+                .take(self.description_rows)
+                .enumerate() {
+                self.window.mv(self.abs_y(separator_row + 1 + i as i32), self.abs_x(0));
+                self.window.addstr(line);
+            }
+        }
+    }
+
+    /// Returns the number of rows the counts/position footer reserves:
+    /// 1 when `show_footer` is set and there's anything to report, 0
+    /// otherwise.
+    fn footer_rows(&self) -> i32 {
+        if self.footer_text().is_some() { 1 } else { 0 }
+    }
+
+    /// Returns the height of the item-list area after reserving the
+    /// footer row (if any) and the description pane's rows (if
+    /// enabled). `top_row`/`selected` address positions
+    /// within this viewport, not the raw window height `n_row`.
+    fn viewport_rows(&self) -> i32 {
+        return (self.n_row - self.footer_rows() - self.description_reserved_rows()).max(0);
+    }
+
+    /// Builds the footer text -- current position, total count, unplayed
+    /// count, and scroll position as a percentage -- or `None` when the
+    /// footer is disabled or there's nothing to show it for.
+    fn footer_text(&self) -> Option<String> {
+        if !self.show_footer {
+            return None;
+        }
+        let borrowed = self.items.borrow();
+        let total = borrowed.len();
+        if total == 0 {
+            return None;
+        }
+        let unplayed = borrowed.iter().fold(0, |acc, item| acc + (!item.is_played() as i32));
+        drop(borrowed);
+
+        let position = self.current_index() + 1;
+        let len = self.display_len().max(1);
+        let percent = ((self.top_row as f64 / len as f64) * 100.0).round() as i32;
+        return Some(format!("{}/{} · {} unplayed · ▼ {}%", position, total, unplayed, percent));
+    }
+
+    /// Draws the footer text, if any, on the window's last content row.
+    /// Returns the number of rows it occupies, mirroring `footer_rows()`.
+    fn print_footer(&self) -> i32 {
+        match self.footer_text() {
+            Some(text) => {
+                let row = self.n_row - 1;
+                self.window.mv(self.abs_y(row), self.abs_x(0));
+                self.window.clrtoeol();
+                self.window.addstr(&text);
+                1
+            },
+            None => 0,
+        }
+    }
+
+    /// Returns the number of items currently addressable by `top_row`/
+    /// `selected`: the length of `filtered_order` while a filter is
+    /// active, or the full item count otherwise.
+    fn display_len(&self) -> i32 {
+        match &self.filter {
+            Some(_) => self.filtered_order.len() as i32,
+            None => self.items.borrow().len() as i32,
+        }
+    }
+
+    /// Maps a position in the (possibly filtered) displayed list back to
+    /// its absolute index into `items`. Identity when no filter is active.
+    fn display_idx(&self, pos: i32) -> i32 {
+        match &self.filter {
+            Some(_) => {
+                if pos < 0 || pos as usize >= self.filtered_order.len() {
+                    pos
+                } else {
+                    self.filtered_order[pos as usize]
+                }
+            },
+            None => pos,
+        }
+    }
+
+    /// Returns the absolute `items` index of the currently selected row.
+    pub fn current_index(&self) -> i32 {
+        self.display_idx(self.top_row + self.selected)
+    }
+
+    /// Sets (or updates) the active fuzzy-filter query, rescoring all
+    /// items against it and narrowing the displayed list down to those
+    /// that match, ordered from best to worst match. The selection is
+    /// reset to the top of the narrowed list.
+    pub fn set_filter(&mut self, query: &str) {
+        if query.is_empty() {
+            self.clear_filter();
+            return;
+        }
+        let mut scored: Vec<(i32, i32)> = self.items.borrow().iter().enumerate()
+            .filter_map(|(idx, item)| {
+                fuzzy_score(item.raw_title(), query)
+                    .map(|score| (score, idx as i32))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        self.filtered_order = scored.into_iter().map(|(_, idx)| idx).collect();
+        self.filter = Some(query.to_string());
+        self.top_row = 0;
+        self.selected = 0;
+        self.update_items();
+    }
+
+    /// Clears the active filter, restoring the full, unfiltered list.
+    pub fn clear_filter(&mut self) {
+        if self.filter.is_none() {
+            return;
+        }
+        self.filter = None;
+        self.filtered_order.clear();
+        self.top_row = 0;
+        self.selected = 0;
+        self.update_items();
+    }
+
     /// Scrolls the menu up or down by `lines` lines. Negative values of
-    /// `lines` will scroll the menu up.
-    /// 
-    /// This function examines the new selected value, ensures it does
-    /// not fall out of bounds, and then updates the pancurses window to
-    /// represent the new visible list.
+    /// `lines` will scroll the menu up. Any value of `lines` is
+    /// supported, including a full screen (for paging) or the entire
+    /// list length (for jump-to-end).
+    ///
+    /// Rather than patching up the window line by line, this recomputes
+    /// `top_row`/`selected` in absolute-index space and does a single
+    /// full redraw: given the absolute cursor index `cur = top_row +
+    /// selected`, the new cursor is `cur' = clamp(cur + lines, 0,
+    /// len-1)`; `top_row` only moves far enough to keep `cur'` in view.
     fn scroll(&mut self, lines: i32) {
         // this happens when there are no items in the list yet
         if self.selected == -1 {
             return;
         }
+        // no room for even one row, e.g. on a too-small terminal
+        if self.viewport_rows() < 1 {
+            return;
+        }
 
-        // TODO: currently only handles scroll value of 1; need to extend
-        // to be able to scroll multiple lines at a time
-        let mut old_selected = self.selected;
-        self.selected += lines;
+        let cur = self.top_row + self.selected;
+        self.jump_to(cur + lines);
+        self.highlight_selected();
+    }
 
-        // don't allow scrolling past last item in list (if shorter than
-        // self.n_row)
-        let abs_bottom = min(self.n_row,
-            (self.items.borrow().len() - 1) as i32);
-        if self.selected > abs_bottom {
-            self.selected = abs_bottom;
-        }
+    /// Scrolls down by one full screen of rows.
+    fn page_down(&mut self) {
+        self.scroll(self.viewport_rows());
+    }
 
-        // scroll list if necessary:
-        // scroll down
-        if self.selected > (self.n_row - 1) {
-            self.selected = self.n_row - 1;
-            if let Some(elem) = self.items.borrow().get((self.top_row + self.n_row) as usize) {
-                self.top_row += 1;
-                self.window.mv(self.abs_y(0), self.abs_x(0));
-                self.window.deleteln();
-                old_selected -= 1;
+    /// Scrolls up by one full screen of rows.
+    fn page_up(&mut self) {
+        self.scroll(-self.viewport_rows());
+    }
 
-                self.window.mv(self.abs_y(self.n_row-1), self.abs_x(-1));
-                self.window.clrtobot();
-                self.window.mvaddstr(self.abs_y(self.n_row-1), self.abs_x(0), elem.get_title(self.n_col as usize));
+    /// Scrolls down by half a screen of rows.
+    fn half_page_down(&mut self) {
+        self.scroll(self.viewport_rows() / 2);
+    }
 
-                self.draw_border();
-            }
+    /// Scrolls up by half a screen of rows.
+    fn half_page_up(&mut self) {
+        self.scroll(-(self.viewport_rows() / 2));
+    }
 
-        // scroll up
-        } else if self.selected < 0 {
-            self.selected = 0;
-            if let Some(elem) = self.items.borrow().get((self.top_row - 1) as usize) {
-                self.top_row -= 1;
-                self.window.mv(self.abs_y(0), 0);
-                self.window.insertln();
-                old_selected += 1;
+    /// Jumps to the first item in the list.
+    fn home(&mut self) {
+        let cur = self.top_row + self.selected;
+        self.scroll(-cur);
+    }
 
-                self.window.mv(self.abs_y(0), self.abs_x(0));
-                self.window.addstr(elem.get_title(self.n_col as usize));
+    /// Jumps to the last item in the list.
+    fn end(&mut self) {
+        let cur = self.top_row + self.selected;
+        let len = self.display_len();
+        self.scroll(len - 1 - cur);
+    }
 
-                self.draw_border();
-            }
+    /// Highlights the currently selected row with the "active" color,
+    /// preserving the bold/normal attribute used to mark unplayed items.
+    fn highlight_selected(&mut self) {
+        if self.selected == -1 {
+            return;
         }
-
-        let old_played = if self.items.borrow().get((self.top_row + old_selected) as usize).unwrap().is_played() {
-            pancurses::A_NORMAL
-        } else {
+        let abs_idx = self.display_idx(self.top_row + self.selected) as usize;
+        let (_, bold) = self.items.borrow()
+            .get(abs_idx).unwrap().status_style();
+        let attr = if bold {
             pancurses::A_BOLD
-        };
-        let new_played = if self.items.borrow().get((self.top_row + self.selected) as usize).unwrap().is_played() {
-            pancurses::A_NORMAL
         } else {
-            pancurses::A_BOLD
+            pancurses::A_NORMAL
         };
-
-        self.window.mvchgat(self.abs_y(old_selected), self.abs_x(-1),
-            self.n_col+3,
-            old_played,
-            self.colors.get(ColorType::Normal));
         self.window.mvchgat(self.abs_y(self.selected), self.abs_x(-1),
             self.n_col+3,
-            new_played,
+            self.colors.highlight_attr(attr),
             self.colors.get(ColorType::HighlightedActive));
         self.window.refresh();
     }
@@ -801,14 +1764,12 @@ impl<T: Clone + Menuable> Menu<T> {
     /// for user input to modify state).
     fn activate(&mut self) {
         if self.selected > -1 {
-            let played = if self.items.borrow().get(self.selected as usize).unwrap().is_played() {
-                pancurses::A_NORMAL
-            } else {
-                pancurses::A_BOLD
-            };
+            let abs_idx = self.display_idx(self.top_row + self.selected) as usize;
+            let (_, bold) = self.items.borrow().get(abs_idx).unwrap().status_style();
+            let attr = if bold { pancurses::A_BOLD } else { pancurses::A_NORMAL };
             self.window.mvchgat(self.abs_y(self.selected), self.abs_x(-1),
                 self.n_col + 3,
-                played,
+                self.colors.highlight_attr(attr),
                 self.colors.get(ColorType::HighlightedActive));
             self.window.refresh();
         }
@@ -816,14 +1777,17 @@ impl<T: Clone + Menuable> Menu<T> {
 
     /// Updates window size
     fn resize(&mut self, n_row: i32, n_col: i32) {
-        self.n_row = n_row;
-        self.n_col = n_col;
+        self.n_row = n_row.max(0);
+        self.n_col = n_col.max(0);
 
         // if resizing moves selected item off screen, scroll the list
-        // upwards to keep same item selected
-        if self.selected > (self.n_row - 1) {
-            self.top_row = self.top_row + self.selected - (self.n_row - 1);
-            self.selected = self.n_row - 1;
+        // upwards to keep same item selected; a terminal so small that
+        // the viewport drops to 0 rows would otherwise drive `selected`
+        // negative
+        let last_row = (self.viewport_rows() - 1).max(0);
+        if self.selected > last_row {
+            self.top_row = self.top_row + self.selected - last_row;
+            self.selected = last_row;
         }
     }
 
@@ -838,6 +1802,27 @@ impl<T: Clone + Menuable> Menu<T> {
     fn abs_x(&self, x: i32) -> i32 {
         return x + 2;
     }
+
+    /// Jumps the selection directly to a position in the displayed
+    /// (possibly filtered) list, scrolling the list just enough to keep
+    /// it visible, and redraws. Used by incremental search to land on a
+    /// match without stepping through `scroll()` one line at a time.
+    fn jump_to(&mut self, index: i32) {
+        let len = self.display_len();
+        if len == 0 {
+            return;
+        }
+        let index = index.clamp(0, len - 1);
+        let viewport_rows = self.viewport_rows();
+
+        if index < self.top_row {
+            self.top_row = index;
+        } else if index >= self.top_row + viewport_rows {
+            self.top_row = index - viewport_rows + 1;
+        }
+        self.selected = index - self.top_row;
+        self.update_items();
+    }
 }
 
 
@@ -845,7 +1830,7 @@ impl Menu<Podcast> {
     /// Returns a cloned reference to the list of episodes from the
     /// currently selected podcast.
     pub fn get_episodes(&self) -> LockVec<Episode> {
-        let index = self.selected + self.top_row;
+        let index = self.display_idx(self.selected + self.top_row);
         return self.items.borrow()
             .get(index as usize).unwrap().episodes.clone();
     }
@@ -854,14 +1839,12 @@ impl Menu<Podcast> {
     /// available for user input to modify state).
     fn deactivate(&mut self) {
         if self.selected > -1 {
-            let played = if self.items.borrow().get(self.selected as usize).unwrap().is_played() {
-                pancurses::A_NORMAL
-            } else {
-                pancurses::A_BOLD
-            };
+            let abs_idx = self.display_idx(self.top_row + self.selected) as usize;
+            let (_, bold) = self.items.borrow().get(abs_idx).unwrap().status_style();
+            let attr = if bold { pancurses::A_BOLD } else { pancurses::A_NORMAL };
             self.window.mvchgat(self.abs_y(self.selected), self.abs_x(-1),
                 self.n_col + 3,
-                played,
+                self.colors.highlight_attr(attr),
                 self.colors.get(ColorType::Highlighted));
             self.window.refresh();
         }
@@ -873,42 +1856,209 @@ impl Menu<Episode> {
     /// available for user input to modify state).
     fn deactivate(&mut self) {
         if self.selected > -1 {
-            let played = if self.items.borrow().get(self.selected as usize).unwrap().is_played() {
-                pancurses::A_NORMAL
-            } else {
-                pancurses::A_BOLD
-            };
+            let abs_idx = self.display_idx(self.top_row + self.selected) as usize;
+            let (_, bold) = self.items.borrow().get(abs_idx).unwrap().status_style();
+            let attr = if bold { pancurses::A_BOLD } else { pancurses::A_NORMAL };
             self.window.mvchgat(self.abs_y(self.selected), self.abs_x(-1),
                 self.n_col + 3,
-                played,
+                attr,
                 self.colors.get(ColorType::Normal));
             self.window.refresh();
         }
     }
 }
 
+/// Pane showing word-wrapped metadata and show notes for the currently
+/// selected episode, to the right of the episode menu. Unlike `Menu<T>`,
+/// it has no selectable rows -- `top_row` only tracks independent
+/// scrolling through the wrapped `lines`.
+#[derive(Debug)]
+struct Details {
+    window: Window,
+    colors: Colors,
+    n_row: i32,
+    n_col: i32,
+    top_row: i32,  // top line of wrapped text shown in window
+    lines: Vec<String>,
+}
+
+impl Details {
+    /// Draws a border around the window, in the theme's neutral `Gray`.
+    /// The details pane is always the rightmost of the three panes, so
+    /// the left border tees into the episode menu's border rather than
+    /// forming a corner.
+    fn draw_border(&self) {
+        self.window.color_set(self.colors.get(ColorType::Gray));
+        self.window.border(
+            pancurses::ACS_VLINE(),
+            pancurses::ACS_VLINE(),
+            pancurses::ACS_HLINE(),
+            pancurses::ACS_HLINE(),
+            pancurses::ACS_TTEE(),
+            pancurses::ACS_URCORNER(),
+            pancurses::ACS_BTEE(),
+            pancurses::ACS_LRCORNER());
+        self.window.color_set(self.colors.get(ColorType::Normal));
+
+        self.window.mvaddstr(0, 2, "Details");
+    }
+
+    /// Rebuilds the wrapped lines of text describing `ep`, resets the
+    /// scroll position to the top, and redraws. Called whenever the
+    /// episode selection changes.
+    fn set_episode(&mut self, ep: Option<&Episode>) {
+        self.top_row = 0;
+        self.lines = match ep {
+            Some(ep) => {
+                let mut lines = vec![ep.title.clone()];
+                let pubdate = ep.pubdate
+                    .map(|pd| pd.format("%B %-d, %Y").to_string())
+                    .unwrap_or_else(|| "no date".to_string());
+                lines.push(format!("{} -- {}", pubdate, ep.format_duration()));
+                lines.push(String::new());
+                lines.extend(wrap_text(&ep.description, self.n_col as usize));
+                lines
+            },
+            None => Vec::new(),
+        };
+        self.render();
+    }
+
+    /// Prints the currently visible slice of `lines`, starting from
+    /// `top_row`, and refreshes the window.
+    fn render(&mut self) {
+        self.window.erase();
+        self.draw_border();
+        for (i, line) in self.lines.iter()
+            .skip(self.top_row as usize)
+            .take(self.n_row as usize)
+            .enumerate() {
+            self.window.mvaddstr(i as i32 + 1, 2, line);
+        }
+        self.window.refresh();
+    }
+
+    /// Scrolls the preview text up or down by `lines`, independent of
+    /// the episode menu's own scroll position.
+    fn scroll(&mut self, lines: i32) {
+        let max_top = (self.lines.len() as i32 - self.n_row).max(0);
+        self.top_row = (self.top_row + lines).clamp(0, max_top);
+        self.render();
+    }
+
+    /// Updates window size.
+    fn resize(&mut self, n_row: i32, n_col: i32) {
+        self.n_row = n_row;
+        self.n_col = n_col;
+    }
+}
+
+/// Greedily word-wraps `text` to lines of at most `width` columns,
+/// measuring display width (rather than byte or char count) so
+/// multi-byte and wide characters wrap correctly. A single word wider
+/// than `width` is not broken up; it goes whole on a line of its own.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for word in text.split_whitespace() {
+        let word_width = UnicodeWidthStr::width(word);
+        let sep_width = if current.is_empty() { 0 } else { 1 };
+        if current_width + sep_width + word_width > width && !current.is_empty() {
+            lines.push(current);
+            current = String::new();
+            current_width = 0;
+        }
+        if !current.is_empty() {
+            current.push(' ');
+            current_width += 1;
+        }
+        current.push_str(word);
+        current_width += word_width;
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    return lines;
+}
+
 // Everything to do with colors ----------------------------------------
 
 /// Enum identifying relevant text states that will be associated with
-/// distinct colors.
+/// distinct colors. `Gray` is a neutral slot for separators and other
+/// chrome that shouldn't compete with the Normal/Highlighted palette.
+/// `Downloaded` lets `Menuable::status_style()` pick out downloaded,
+/// unplayed episodes from plain unplayed/played ones. `InProgress` is a
+/// themable slot that no `status_style()` implementation returns.
 #[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
-enum ColorType {
+pub enum ColorType {
     Normal,
     Highlighted,
     HighlightedActive,
     Error,
+    Gray,
+    Downloaded,
+    InProgress,
+}
+
+/// A single foreground/background pair for one `ColorType` slot. A `bg`
+/// of `None` means "use the terminal's default background" rather than
+/// forcing a specific color -- `set_colors()` then enables the terminal's
+/// default colors via `use_default_colors()`.
+#[derive(Debug, Clone, Copy)]
+pub struct ThemeColor {
+    pub fg: i16,
+    pub bg: Option<i16>,
+}
+
+/// A full, named color palette, as loaded from the user's config file.
+/// Each `ColorType` slot gets its own foreground/background pair, so
+/// users can ship a light or solarized theme without recompiling.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub name: String,
+    pub normal: ThemeColor,
+    pub highlighted: ThemeColor,
+    pub highlighted_active: ThemeColor,
+    pub error: ThemeColor,
+    pub gray: ThemeColor,
+    pub downloaded: ThemeColor,
+    pub in_progress: ThemeColor,
+}
+
+impl Default for Theme {
+    /// The built-in palette shellcaster has always shipped: a black
+    /// background with a custom-tuned white/yellow foreground.
+    fn default() -> Self {
+        return Theme {
+            name: "default".to_string(),
+            normal: ThemeColor { fg: pancurses::COLOR_WHITE, bg: Some(pancurses::COLOR_BLACK) },
+            highlighted: ThemeColor { fg: pancurses::COLOR_BLACK, bg: Some(pancurses::COLOR_WHITE) },
+            highlighted_active: ThemeColor { fg: pancurses::COLOR_BLACK, bg: Some(pancurses::COLOR_YELLOW) },
+            error: ThemeColor { fg: pancurses::COLOR_RED, bg: Some(pancurses::COLOR_BLACK) },
+            gray: ThemeColor { fg: pancurses::COLOR_WHITE, bg: Some(pancurses::COLOR_BLACK) },
+            downloaded: ThemeColor { fg: pancurses::COLOR_CYAN, bg: Some(pancurses::COLOR_BLACK) },
+            in_progress: ThemeColor { fg: pancurses::COLOR_MAGENTA, bg: Some(pancurses::COLOR_BLACK) },
+        };
+    }
 }
 
 /// Keeps a hashmap associating ColorTypes with ncurses color pairs.
+/// `has_color` records whether the terminal had any usable color pairs
+/// at all, so callers can fall back to bold/reverse attributes alone
+/// when it doesn't.
 #[derive(Debug, Clone)]
 struct Colors {
     map: HashMap<ColorType, i16>,
+    has_color: bool,
 }
 
 impl Colors {
-    fn new() -> Colors {
+    fn new(has_color: bool) -> Colors {
         return Colors {
             map: HashMap::new(),
+            has_color,
         }
     }
 
@@ -919,36 +2069,262 @@ impl Colors {
     fn get(&self, color: ColorType) -> i16 {
         return *self.map.get(&color).unwrap();
     }
+
+    /// Adds a reverse-video fallback on top of `attr` when the terminal
+    /// has no usable color pairs, so a highlighted or selected row
+    /// stays visually distinct even without color.
+    fn highlight_attr(&self, attr: pancurses::chtype) -> pancurses::chtype {
+        if self.has_color {
+            return attr;
+        }
+        return attr | pancurses::A_REVERSE;
+    }
+}
+
+/// How much of the palette a terminal can actually display, probed
+/// once at startup so `set_colors()` can decide how aggressively to
+/// customize it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorCapability {
+    /// `init_color()` works, so a theme's custom RGB values (e.g. the
+    /// built-in theme's tuned white/yellow) can be redefined exactly.
+    Full,
+    /// Color pairs are supported, but redefining color values is not, or
+    /// the terminal reports fewer than 256 colors -- fall back to the
+    /// standard ANSI 8/16 indices a theme names.
+    Limited,
+    /// No color support at all -- pairs are never initialized, and
+    /// everything falls back to bold/reverse attributes.
+    None,
+}
+
+/// Probes `has_colors()`, `can_change_color()`, and the reported color
+/// count to decide how much of the palette this terminal can display.
+/// Must be called after `pancurses::start_color()`.
+fn detect_color_capability() -> ColorCapability {
+    if !pancurses::has_colors() {
+        return ColorCapability::None;
+    }
+    if pancurses::can_change_color() && pancurses::COLORS() >= 256 {
+        return ColorCapability::Full;
+    }
+    return ColorCapability::Limited;
 }
 
+/// Sets up hashmap for ColorTypes in app, initiates the color pairs
+/// from `theme`, and sets up ncurses color pairs. On a terminal with
+/// full color support, the built-in theme's classic RGB-tuned
+/// white/yellow are redefined as before; a user-specified theme is
+/// taken as-is, using whatever standard ANSI color indices it names.
+/// On a terminal without `init_color()` support, that redefinition is
+/// skipped and the theme's plain ANSI indices are used instead; on a
+/// terminal with no color support at all, no pairs are initialized and
+/// differentiation falls back entirely to bold/reverse attributes (see
+/// `Colors::highlight_attr`).
+fn set_colors(theme: &Theme) -> Colors {
+    let capability = detect_color_capability();
+    let mut colors = Colors::new(capability != ColorCapability::None);
+
+    if capability == ColorCapability::None {
+        for color in [ColorType::Normal, ColorType::Highlighted, ColorType::HighlightedActive,
+            ColorType::Error, ColorType::Gray, ColorType::Downloaded, ColorType::InProgress].iter() {
+            colors.insert(*color, 0);
+        }
+        return colors;
+    }
 
-/// Sets up hashmap for ColorTypes in app, initiates color palette, and
-/// sets up ncurses color pairs.
-fn set_colors() -> Colors {
     // set up a hashmap for easier reference
-    let mut colors = Colors::new();
     colors.insert(ColorType::Normal, 0);
     colors.insert(ColorType::Highlighted, 1);
     colors.insert(ColorType::HighlightedActive, 2);
     colors.insert(ColorType::Error, 3);
+    colors.insert(ColorType::Gray, 4);
+    colors.insert(ColorType::Downloaded, 5);
+    colors.insert(ColorType::InProgress, 6);
+
+    if theme.name == "default" && capability == ColorCapability::Full {
+        pancurses::init_color(pancurses::COLOR_WHITE, 680, 680, 680);
+        pancurses::init_color(pancurses::COLOR_YELLOW, 820, 643, 0);
+    }
+
+    if [theme.normal.bg, theme.highlighted.bg, theme.highlighted_active.bg,
+        theme.error.bg, theme.gray.bg, theme.downloaded.bg, theme.in_progress.bg]
+        .iter().any(|bg| bg.is_none()) {
+        pancurses::use_default_colors();
+    }
 
-    // specify some colors by RGB value
-    pancurses::init_color(pancurses::COLOR_WHITE, 680, 680, 680);
-    pancurses::init_color(pancurses::COLOR_YELLOW, 820, 643, 0);
-
-    // instantiate curses color pairs
-    pancurses::init_pair(colors.get(ColorType::Normal),
-        pancurses::COLOR_WHITE,
-        pancurses::COLOR_BLACK);
-    pancurses::init_pair(colors.get(ColorType::Highlighted),
-        pancurses::COLOR_BLACK,
-        pancurses::COLOR_WHITE);
-    pancurses::init_pair(colors.get(ColorType::HighlightedActive),
-        pancurses::COLOR_BLACK,
-        pancurses::COLOR_YELLOW);
-    pancurses::init_pair(colors.get(ColorType::Error),
-        pancurses::COLOR_RED,
-        pancurses::COLOR_BLACK);
+    init_theme_pair(colors.get(ColorType::Normal), theme.normal);
+    init_theme_pair(colors.get(ColorType::Highlighted), theme.highlighted);
+    init_theme_pair(colors.get(ColorType::HighlightedActive), theme.highlighted_active);
+    init_theme_pair(colors.get(ColorType::Error), theme.error);
+    init_theme_pair(colors.get(ColorType::Gray), theme.gray);
+    init_theme_pair(colors.get(ColorType::Downloaded), theme.downloaded);
+    init_theme_pair(colors.get(ColorType::InProgress), theme.in_progress);
 
     return colors;
+}
+
+/// Instantiates a single ncurses color pair from a `ThemeColor`,
+/// falling back to the terminal's default background (-1) when the
+/// theme doesn't specify one.
+fn init_theme_pair(pair: i16, color: ThemeColor) {
+    pancurses::init_pair(pair, color.fg, color.bg.unwrap_or(-1));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::sync::Once;
+
+    static CURSES_INIT: Once = Once::new();
+
+    /// ncurses needs `initscr()` called once per process before any
+    /// window can be created; repeated calls would re-enter curses mode
+    /// and panic, so this only runs the first time a test asks for one.
+    fn ensure_curses() {
+        CURSES_INIT.call_once(|| {
+            pancurses::initscr();
+        });
+    }
+
+    /// Colors with no actual pairs initialized, mirroring the
+    /// `ColorCapability::None` branch of `set_colors()` -- good enough
+    /// for tests that don't care what a color resolves to.
+    fn test_colors() -> Colors {
+        let mut colors = Colors::new(false);
+        for color in [ColorType::Normal, ColorType::Highlighted, ColorType::HighlightedActive,
+            ColorType::Error, ColorType::Gray, ColorType::Downloaded, ColorType::InProgress].iter() {
+            colors.insert(*color, 0);
+        }
+        return colors;
+    }
+
+    /// Builds a `Menu<Episode>` over a handful of episodes, with a real
+    /// (but tiny) pancurses window of `n_row` usable rows and `n_col`
+    /// usable columns -- i.e. the same numbers `Menu::n_row`/`n_col`
+    /// store, not the underlying window's bordered size.
+    fn create_menu(n_row: i32, n_col: i32, top_row: i32, selected: i32) -> Menu<Episode> {
+        ensure_curses();
+        let titles = vec![
+            "A Very Cool Episode",
+            "This is a very long episode title but we'll get through it together",
+            "An episode with le Unicodé",
+            "How does an episode with emoji sound? 😉",
+            "Here's another title",
+            "Un titre, c'est moi!",
+            "One more just for good measure",
+        ];
+        let mut items = Vec::new();
+        for (i, t) in titles.iter().enumerate() {
+            items.push(Episode {
+                id: Some(i as i64),
+                pod_id: Some(1),
+                title: t.to_string(),
+                url: String::new(),
+                description: String::new(),
+                pubdate: Some(Utc::now()),
+                duration: Some(12345),
+                path: None,
+                played: i % 2 == 0,
+            });
+        }
+
+        return Menu {
+            window: newwin(n_row + 2, n_col + 5, 0, 0),
+            screen_pos: 0,
+            colors: test_colors(),
+            title: "Episodes".to_string(),
+            items: LockVec::new(items),
+            n_row: n_row,
+            n_col: n_col,
+            top_row: top_row,
+            selected: selected,
+            filter: None,
+            filtered_order: Vec::new(),
+            show_footer: false,
+            description_rows: 0,
+        };
+    }
+
+    #[test]
+    fn scroll_up_recomputes_top_row_and_selected() {
+        let mut menu = create_menu(5, 65, 2, 0);
+        menu.update_items();
+
+        menu.scroll(-1);
+
+        // cur = top_row(2) + selected(0) = 2; scrolling by -1 lands on
+        // absolute index 1, which is already above top_row, so top_row
+        // follows it down to 1 and selected stays at the window's top
+        assert_eq!(menu.top_row, 1);
+        assert_eq!(menu.selected, 0);
+    }
+
+    #[test]
+    fn scroll_down_past_viewport_moves_top_row() {
+        let mut menu = create_menu(3, 65, 0, 0);
+        menu.update_items();
+
+        // the viewport only shows 3 rows, so scrolling down by 4 should
+        // push top_row forward rather than just moving `selected` past
+        // the bottom of the window
+        menu.scroll(4);
+
+        assert_eq!(menu.current_index(), 4);
+        assert!(menu.top_row > 0);
+    }
+
+    #[test]
+    fn footer_text_reports_position_total_and_unplayed() {
+        let mut menu = create_menu(5, 65, 0, 0);
+        menu.show_footer = true;
+        menu.update_items();
+
+        let footer = menu.footer_text().unwrap();
+
+        // 7 episodes total, played on every even index (0, 2, 4, 6),
+        // so 3 remain unplayed; selection starts on item 0 (position 1)
+        assert!(footer.starts_with("1/7"));
+        assert!(footer.contains("3 unplayed"));
+    }
+
+    #[test]
+    fn footer_text_absent_when_disabled_or_empty() {
+        let mut menu = create_menu(5, 65, 0, 0);
+        menu.update_items();
+        assert!(menu.footer_text().is_none());
+
+        menu.show_footer = true;
+        menu.items = LockVec::new(Vec::new());
+        assert!(menu.footer_text().is_none());
+    }
+
+    #[test]
+    fn too_small_terminal_shows_placeholder_without_panicking() {
+        // n_row(1) leaves a single row, but reserving 2 description
+        // rows (plus its separator) still drives viewport_rows() below
+        // 1 -- the same "too small to show even one item" case a tiny
+        // real terminal would hit
+        let mut menu = create_menu(1, 65, 3, 2);
+        menu.description_rows = 2;
+
+        menu.update_items();
+
+        assert_eq!(menu.top_row, 0);
+        assert_eq!(menu.selected, -1);
+    }
+
+    #[test]
+    fn scroll_is_noop_when_viewport_too_small() {
+        let mut menu = create_menu(1, 65, 0, 0);
+        menu.description_rows = 2;
+        menu.update_items();
+
+        menu.scroll(3);
+
+        assert_eq!(menu.top_row, 0);
+        assert_eq!(menu.selected, -1);
+    }
 }
\ No newline at end of file